@@ -0,0 +1,163 @@
+// src/breaker.rs
+// 학교별 회로 차단기: 계속 실패하는 사이트에는 잠시 요청을 보내지 않음
+//
+// Closed(정상) → 연속 일시적 실패가 `threshold`회 → Open(차단, `cooldown` 동안 즉시 실패)
+// → 대기 시간이 지나면 HalfOpen(시험 요청 1건만 허용, 나머지는 그 결과가 나올 때까지 즉시 실패)
+// → 성공하면 Closed, 실패하면 다시 Open
+
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    Closed,
+    Open { until: Instant },
+    /// 시험 요청을 보낸 시각 (보낸 게 없으면 None)
+    HalfOpen { probing: Option<Instant> },
+}
+
+#[derive(Debug)]
+struct Entry {
+    state: State,
+    consecutive_failures: u32,
+    last_error: Option<String>,
+}
+
+impl Default for Entry {
+    fn default() -> Self {
+        Entry {
+            state: State::Closed,
+            consecutive_failures: 0,
+            last_error: None,
+        }
+    }
+}
+
+/// 헬스 체크/로그용 차단기 상태
+#[derive(Debug, Clone, Serialize)]
+pub struct BreakerStatus {
+    pub school: &'static str,
+    /// "closed" | "open" | "half-open"
+    pub state: &'static str,
+    pub consecutive_failures: u32,
+    /// Open 상태일 때 남은 차단 시간(초)
+    pub retry_in_secs: Option<u64>,
+    pub last_error: Option<String>,
+}
+
+pub struct CircuitBreakers {
+    threshold: u32,
+    cooldown: Duration,
+    entries: Mutex<HashMap<&'static str, Entry>>,
+}
+
+impl CircuitBreakers {
+    /// `threshold`가 0이면 차단하지 않음
+    pub fn new(threshold: u32, cooldown: Duration) -> Self {
+        CircuitBreakers {
+            threshold,
+            cooldown,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// 요청을 보내도 되는지. 차단 중이면 남은 시간.
+    /// half-open에서 통과한 요청은 시험 요청이므로, 끝나면 반드시 `record_success`/`record_failure`/`release` 중 하나를 부름
+    pub fn check(&self, school: &'static str) -> Result<(), Duration> {
+        let mut entries = self.entries.lock().unwrap();
+        let entry = entries.entry(school).or_default();
+        let now = Instant::now();
+        match entry.state {
+            State::Closed => Ok(()),
+            State::Open { until } if now < until => Err(until - now),
+            State::Open { .. } => {
+                entry.state = State::HalfOpen { probing: Some(now) };
+                eprintln!("[BREAKER] {school}: 차단 시간 종료 → half-open (시험 요청 1건 허용)");
+                Ok(())
+            }
+            // 시험 요청이 진행 중이면 기다림. 차단 시간만큼 지나도 결과가 없으면(요청이 취소됨 등) 새로 시험
+            State::HalfOpen { probing: Some(at) } if now - at < self.cooldown => {
+                Err(self.cooldown - (now - at))
+            }
+            State::HalfOpen { .. } => {
+                entry.state = State::HalfOpen { probing: Some(now) };
+                Ok(())
+            }
+        }
+    }
+
+    /// 시험 요청이 사이트에 닿지 않고 끝남 (robots.txt 금지 등). 다음 요청이 다시 시험
+    pub fn release(&self, school: &'static str) {
+        let mut entries = self.entries.lock().unwrap();
+        if let Some(entry) = entries.get_mut(school) {
+            if let State::HalfOpen { probing } = &mut entry.state {
+                *probing = None;
+            }
+        }
+    }
+
+    /// 사이트가 응답함 (2xx가 아니어도 일시적 오류가 아니면 성공으로 봄)
+    pub fn record_success(&self, school: &'static str) {
+        let mut entries = self.entries.lock().unwrap();
+        let entry = entries.entry(school).or_default();
+        if entry.state != State::Closed {
+            eprintln!("[BREAKER] {school}: 요청 성공 → closed");
+        }
+        *entry = Entry::default();
+    }
+
+    /// 재시도까지 모두 실패한 일시적 오류 1건
+    pub fn record_failure(&self, school: &'static str, error: &str) {
+        let mut entries = self.entries.lock().unwrap();
+        let entry = entries.entry(school).or_default();
+        entry.consecutive_failures += 1;
+        entry.last_error = Some(error.to_string());
+        if self.threshold == 0 {
+            return;
+        }
+        let trip = match entry.state {
+            State::HalfOpen { .. } => true,
+            State::Closed => entry.consecutive_failures >= self.threshold,
+            State::Open { .. } => false,
+        };
+        if trip {
+            entry.state = State::Open {
+                until: Instant::now() + self.cooldown,
+            };
+            eprintln!(
+                "[BREAKER] {school}: 연속 {}회 실패 → open ({}초 동안 요청 차단)",
+                entry.consecutive_failures,
+                self.cooldown.as_secs()
+            );
+        }
+    }
+
+    /// 한 번이라도 요청한 학교들의 현재 상태 (학교 키 순)
+    pub fn snapshot(&self) -> Vec<BreakerStatus> {
+        let entries = self.entries.lock().unwrap();
+        let now = Instant::now();
+        let mut statuses: Vec<_> = entries
+            .iter()
+            .map(|(school, e)| {
+                let (state, retry_in_secs) = match e.state {
+                    State::Closed => ("closed", None),
+                    State::HalfOpen { .. } => ("half-open", None),
+                    State::Open { until } if until > now => ("open", Some((until - now).as_secs())),
+                    // 대기 시간이 지났으면 다음 요청이 시험 요청이 됨
+                    State::Open { .. } => ("half-open", None),
+                };
+                BreakerStatus {
+                    school,
+                    state,
+                    consecutive_failures: e.consecutive_failures,
+                    retry_in_secs,
+                    last_error: e.last_error.clone(),
+                }
+            })
+            .collect();
+        statuses.sort_by_key(|s| s.school);
+        statuses
+    }
+}
@@ -0,0 +1,99 @@
+// src/cache.rs
+// 목록 페이지 조건부 GET용 디스크 캐시 (ETag/Last-Modified + 본문)
+//
+// 다음 요청에 If-None-Match/If-Modified-Since를 붙이고, 304가 오면 저장해 둔 본문을 다시 파싱합니다.
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+/// 캐시에 저장한 응답 1건
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedPage {
+    pub url: String,
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+    pub body: String,
+    /// 저장 시각 (RFC 3339, KST)
+    pub stored_at: String,
+}
+
+/// 조건부 요청 집계
+#[derive(Debug, Clone, Copy, Default, Serialize)]
+pub struct CacheStats {
+    /// 304로 캐시 본문을 재사용한 횟수
+    pub hits: u32,
+    /// 200으로 새 본문을 받은 횟수
+    pub misses: u32,
+}
+
+pub struct HttpCache {
+    /// None이면 캐시 끔
+    dir: Option<PathBuf>,
+    hits: AtomicU32,
+    misses: AtomicU32,
+}
+
+impl HttpCache {
+    pub fn new(dir: Option<PathBuf>) -> Self {
+        HttpCache {
+            dir,
+            hits: AtomicU32::new(0),
+            misses: AtomicU32::new(0),
+        }
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.dir.is_some()
+    }
+
+    fn path(&self, url: &str) -> Option<PathBuf> {
+        Some(self.dir.as_ref()?.join(format!("{:016x}.json", fnv1a64(url))))
+    }
+
+    /// 같은 URL로 저장한 응답. 해시 충돌/손상 파일은 없는 것으로 봄
+    pub fn load(&self, url: &str) -> Option<CachedPage> {
+        let raw = fs::read_to_string(self.path(url)?).ok()?;
+        serde_json::from_str::<CachedPage>(&raw)
+            .ok()
+            .filter(|p| p.url == url)
+    }
+
+    /// 검증자(ETag/Last-Modified)가 있는 응답만 저장. 실패해도 수집은 계속
+    pub fn store(&self, page: &CachedPage) {
+        if page.etag.is_none() && page.last_modified.is_none() {
+            return;
+        }
+        let Some(path) = self.path(&page.url) else { return };
+        let result = path
+            .parent()
+            .map_or(Ok(()), fs::create_dir_all)
+            .and_then(|_| fs::write(&path, serde_json::to_vec(page).unwrap_or_default()));
+        if let Err(e) = result {
+            eprintln!("[CACHE] 저장 실패 ({}): {e}", path.display());
+        }
+    }
+
+    pub fn record_hit(&self) {
+        self.hits.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_miss(&self) {
+        self.misses.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// 캐시 파일 이름/본문 비교용 해시 (실행마다 같아야 하므로 std 해셔 대신 FNV-1a)
+pub(crate) fn fnv1a64(s: &str) -> u64 {
+    s.bytes().fold(0xcbf29ce484222325, |h, b| {
+        (h ^ b as u64).wrapping_mul(0x100000001b3)
+    })
+}
@@ -0,0 +1,315 @@
+// src/config.rs
+//! 실행 설정. 환경 변수(`UNIV_CRAWLER_*`)를 먼저 읽고, 명령행 인자로 덮어씁니다.
+
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::model::PinnedMode;
+use crate::transport::CassetteMode;
+
+#[derive(Debug, Clone)]
+pub struct Config {
+    /// 학교별 목록 최대 페이지 수 (`UNIV_CRAWLER_MAX_PAGES`, `--max-pages`)
+    pub max_pages: usize,
+    /// 한 번에 수집할 학교별 최대 공지 수 (`UNIV_CRAWLER_MAX_ITEMS`, `--max-items`)
+    pub max_items: usize,
+    /// 피드에 싣는 학교별 최대 공지 수 — 저장소에 쌓인 글 중 최근 것 (`UNIV_CRAWLER_FEED_ITEMS`, `--feed-items`)
+    pub feed_items: usize,
+    /// 게시일과 게시판에서 마지막으로 본 날이 모두 이보다 오래된 글은 피드에서 뺌(일), 0이면 제한 없음
+    /// (`UNIV_CRAWLER_FEED_MAX_AGE_DAYS`, `--feed-max-age-days`)
+    pub feed_max_age_days: u32,
+    /// HTTP 서버가 학교별 피드를 다시 수집하기 전까지 캐시해 두는 시간, 0이면 요청마다 수집
+    /// (`UNIV_CRAWLER_FEED_TTL_SECS`, `--feed-ttl-secs`)
+    pub feed_ttl: Duration,
+    /// 상세 페이지까지 수집 (`UNIV_CRAWLER_DETAILS=1`, `--details`)
+    pub details: bool,
+    /// 상단 고정 글 처리 (`UNIV_CRAWLER_PINNED`, `--pinned include|exclude|only`)
+    pub pinned: PinnedMode,
+    /// 학교/상세 페이지 동시 요청 수 (`UNIV_CRAWLER_CONCURRENCY`, `--concurrency`)
+    pub concurrency: usize,
+    /// 수정된 글의 피드 설명 앞에 무엇이 바뀌었는지 표시 (`UNIV_CRAWLER_CHANGE_NOTES=1`, `--change-notes`)
+    pub change_notes: bool,
+    /// 실행 간에 남길 상태(HTTP 캐시, 헬스 기준 기록 등) 디렉터리 (`UNIV_CRAWLER_STATE_DIR`)
+    pub state_dir: PathBuf,
+    /// 공지 저장소(SQLite) 파일 (`UNIV_CRAWLER_DB`, 기본 `<state_dir>/notices.db`)
+    pub db_path: PathBuf,
+    /// 서버(또는 `public/`을 배포한 곳)가 바깥에 보이는 주소. OpenSearch 문서의 검색 URL과 피드 자신의 주소에 씀
+    /// (`UNIV_CRAWLER_PUBLIC_URL`, `--public-url`)
+    pub public_url: String,
+    pub http: HttpConfig,
+}
+
+/// 공용 HTTP 클라이언트 설정 (`http::HttpClient::new`에서 사용)
+#[derive(Debug, Clone)]
+pub struct HttpConfig {
+    /// User-Agent 전체 (`UNIV_CRAWLER_USER_AGENT`). 없으면 봇 식별자 + 연락처로 조립
+    pub user_agent: Option<String>,
+    /// 봇 식별자에 붙일 연락처 URL (`UNIV_CRAWLER_CONTACT_URL`)
+    pub contact_url: String,
+    /// 요청 전체 타임아웃 (`UNIV_CRAWLER_TIMEOUT_SECS`)
+    pub timeout: Duration,
+    /// 연결 타임아웃 (`UNIV_CRAWLER_CONNECT_TIMEOUT_SECS`)
+    pub connect_timeout: Duration,
+    /// 프록시 URL (`UNIV_CRAWLER_PROXY`)
+    pub proxy: Option<String>,
+    /// 모든 요청에 붙일 헤더 (`UNIV_CRAWLER_HEADERS="Name: value|Name2: value2"`)
+    pub headers: Vec<(String, String)>,
+    /// 호스트별 유휴 연결 최대 개수 (`UNIV_CRAWLER_POOL_MAX_IDLE`)
+    pub pool_max_idle_per_host: usize,
+    /// 일시적 오류(연결 실패, 5xx, 429) 재시도 횟수 (`UNIV_CRAWLER_MAX_RETRIES`)
+    pub max_retries: u32,
+    /// 첫 재시도 대기 시간. 이후 2배씩 늘고 지터를 섞음 (`UNIV_CRAWLER_RETRY_BASE_MS`)
+    pub retry_base: Duration,
+    /// 학교별 연속 실패가 이 횟수에 닿으면 요청 차단, 0이면 끔 (`UNIV_CRAWLER_BREAKER_THRESHOLD`)
+    pub breaker_threshold: u32,
+    /// 차단 유지 시간 (`UNIV_CRAWLER_BREAKER_COOLDOWN_SECS`)
+    pub breaker_cooldown: Duration,
+    /// robots.txt 준수 (`UNIV_CRAWLER_ROBOTS=0`이면 끔)
+    pub respect_robots: bool,
+    /// 호스트별 초당 최대 요청 수, 0이면 제한 없음 (`UNIV_CRAWLER_HOST_RPS`).
+    /// robots.txt의 Crawl-delay가 더 길면 그 간격을 따름
+    pub host_rps: f64,
+    /// 호스트별 동시 요청 수 (`UNIV_CRAWLER_HOST_CONCURRENCY`)
+    pub host_concurrency: usize,
+    /// 목록 페이지 조건부 GET 캐시 위치. None이면 끔
+    /// (`Config`가 `<state_dir>/http-cache`로 채움, `UNIV_CRAWLER_HTTP_CACHE=0`/`--no-cache`면 끔)
+    pub cache_dir: Option<PathBuf>,
+    /// 응답 녹화/재생 파일 (`UNIV_CRAWLER_CASSETTE`, `UNIV_CRAWLER_CASSETTE_MODE=record|replay`,
+    /// `--record <파일>`, `--replay <파일>`)
+    pub cassette: Option<(PathBuf, CassetteMode)>,
+}
+
+impl Default for HttpConfig {
+    fn default() -> Self {
+        HttpConfig {
+            user_agent: None,
+            contact_url: "https://github.com/soeun-727/univ-crawler".to_string(),
+            timeout: Duration::from_secs(10),
+            connect_timeout: Duration::from_secs(5),
+            proxy: None,
+            headers: vec![("Accept-Language".to_string(), "ko-KR,ko;q=0.9".to_string())],
+            pool_max_idle_per_host: 4,
+            max_retries: 2,
+            retry_base: Duration::from_millis(500),
+            breaker_threshold: 5,
+            breaker_cooldown: Duration::from_secs(300),
+            respect_robots: true,
+            host_rps: 1.0,
+            host_concurrency: 2,
+            cache_dir: None,
+            cassette: None,
+        }
+    }
+}
+
+impl HttpConfig {
+    pub fn from_env() -> Self {
+        let d = HttpConfig::default();
+        HttpConfig {
+            user_agent: env_string("UNIV_CRAWLER_USER_AGENT"),
+            contact_url: env_string("UNIV_CRAWLER_CONTACT_URL").unwrap_or(d.contact_url),
+            timeout: Duration::from_secs(env_or("UNIV_CRAWLER_TIMEOUT_SECS", d.timeout.as_secs())),
+            connect_timeout: Duration::from_secs(env_or(
+                "UNIV_CRAWLER_CONNECT_TIMEOUT_SECS",
+                d.connect_timeout.as_secs(),
+            )),
+            proxy: env_string("UNIV_CRAWLER_PROXY"),
+            headers: env_string("UNIV_CRAWLER_HEADERS")
+                .map(|v| parse_headers(&v))
+                .unwrap_or(d.headers),
+            pool_max_idle_per_host: env_or("UNIV_CRAWLER_POOL_MAX_IDLE", d.pool_max_idle_per_host),
+            max_retries: env_or("UNIV_CRAWLER_MAX_RETRIES", d.max_retries),
+            retry_base: Duration::from_millis(env_or(
+                "UNIV_CRAWLER_RETRY_BASE_MS",
+                d.retry_base.as_millis() as u64,
+            )),
+            breaker_threshold: env_or("UNIV_CRAWLER_BREAKER_THRESHOLD", d.breaker_threshold),
+            breaker_cooldown: Duration::from_secs(env_or(
+                "UNIV_CRAWLER_BREAKER_COOLDOWN_SECS",
+                d.breaker_cooldown.as_secs(),
+            )),
+            respect_robots: env_flag("UNIV_CRAWLER_ROBOTS", d.respect_robots),
+            host_rps: env_or("UNIV_CRAWLER_HOST_RPS", d.host_rps),
+            host_concurrency: env_or("UNIV_CRAWLER_HOST_CONCURRENCY", d.host_concurrency),
+            cache_dir: d.cache_dir,
+            cassette: env_string("UNIV_CRAWLER_CASSETTE").map(|path| {
+                (PathBuf::from(path), env_or("UNIV_CRAWLER_CASSETTE_MODE", CassetteMode::Replay))
+            }),
+        }
+    }
+
+    /// 카세트 녹화 중인지. 녹화할 때는 HTTP 캐시를 쓰지 않음
+    pub fn is_recording(&self) -> bool {
+        matches!(self.cassette, Some((_, CassetteMode::Record)))
+    }
+
+    /// 실제로 보낼 User-Agent. 대학 측이 누가 긁어 가는지 알 수 있도록 연락처를 포함
+    pub fn user_agent(&self) -> String {
+        match &self.user_agent {
+            Some(ua) => ua.clone(),
+            None => format!(
+                "Mozilla/5.0 (compatible; univ-crawler/{}; +{})",
+                env!("CARGO_PKG_VERSION"),
+                self.contact_url
+            ),
+        }
+    }
+}
+
+/// "Name: value|Name2: value2" → [(Name, value), (Name2, value2)]
+fn parse_headers(raw: &str) -> Vec<(String, String)> {
+    raw.split('|')
+        .filter_map(|kv| kv.split_once(':'))
+        .map(|(k, v)| (k.trim().to_string(), v.trim().to_string()))
+        .filter(|(k, _)| !k.is_empty())
+        .collect()
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        let state_dir = PathBuf::from(".crawler");
+        Config {
+            max_pages: 3,
+            max_items: 30,
+            feed_items: 50,
+            feed_max_age_days: 180,
+            feed_ttl: Duration::from_secs(600),
+            details: false,
+            pinned: PinnedMode::Include,
+            concurrency: 4,
+            change_notes: false,
+            http: HttpConfig {
+                cache_dir: Some(state_dir.join("http-cache")),
+                ..HttpConfig::default()
+            },
+            db_path: state_dir.join("notices.db"),
+            public_url: "http://localhost:8080".to_string(),
+            state_dir,
+        }
+    }
+}
+
+impl Config {
+    pub fn from_env() -> Self {
+        let d = Config::default();
+        let state_dir = env_string("UNIV_CRAWLER_STATE_DIR").map_or(d.state_dir, PathBuf::from);
+        let http_cache = env_flag("UNIV_CRAWLER_HTTP_CACHE", true);
+        let http = HttpConfig::from_env();
+        // 녹화 중에는 조건부 요청을 보내지 않음 (304 빈 응답이 녹화되면 재생할 수 없음)
+        let http_cache = http_cache && !http.is_recording();
+        Config {
+            max_pages: env_or("UNIV_CRAWLER_MAX_PAGES", d.max_pages),
+            max_items: env_or("UNIV_CRAWLER_MAX_ITEMS", d.max_items),
+            feed_items: env_or("UNIV_CRAWLER_FEED_ITEMS", d.feed_items),
+            feed_max_age_days: env_or("UNIV_CRAWLER_FEED_MAX_AGE_DAYS", d.feed_max_age_days),
+            feed_ttl: Duration::from_secs(env_or("UNIV_CRAWLER_FEED_TTL_SECS", d.feed_ttl.as_secs())),
+            details: env_flag("UNIV_CRAWLER_DETAILS", d.details),
+            pinned: env_or("UNIV_CRAWLER_PINNED", d.pinned),
+            concurrency: env_or("UNIV_CRAWLER_CONCURRENCY", d.concurrency),
+            change_notes: env_flag("UNIV_CRAWLER_CHANGE_NOTES", d.change_notes),
+            http: HttpConfig {
+                cache_dir: http_cache.then(|| state_dir.join("http-cache")),
+                ..http
+            },
+            db_path: env_string("UNIV_CRAWLER_DB").map_or_else(|| state_dir.join("notices.db"), PathBuf::from),
+            public_url: env_string("UNIV_CRAWLER_PUBLIC_URL").map_or(d.public_url, |u| u.trim_end_matches('/').to_string()),
+            state_dir,
+        }
+    }
+
+    /// `--max-pages 5` / `--max-pages=5` 형태의 인자 반영
+    pub fn apply_args(mut self, args: &[String]) -> Self {
+        if let Some(v) = arg_value(args, "--max-pages") {
+            self.max_pages = v;
+        }
+        if let Some(v) = arg_value(args, "--max-items") {
+            self.max_items = v;
+        }
+        if let Some(v) = arg_value(args, "--feed-items") {
+            self.feed_items = v;
+        }
+        if let Some(v) = arg_value(args, "--feed-max-age-days") {
+            self.feed_max_age_days = v;
+        }
+        if let Some(v) = arg_value(args, "--feed-ttl-secs") {
+            self.feed_ttl = Duration::from_secs(v);
+        }
+        if args.iter().any(|a| a == "--details") {
+            self.details = true;
+        }
+        if let Some(v) = arg_value(args, "--pinned") {
+            self.pinned = v;
+        }
+        if let Some(v) = arg_value(args, "--concurrency") {
+            self.concurrency = v;
+        }
+        if args.iter().any(|a| a == "--change-notes") {
+            self.change_notes = true;
+        }
+        if args.iter().any(|a| a == "--no-cache") {
+            self.http.cache_dir = None;
+        }
+        if let Some(path) = arg_value::<PathBuf>(args, "--record") {
+            self.http.cassette = Some((path, CassetteMode::Record));
+        }
+        if let Some(path) = arg_value::<PathBuf>(args, "--replay") {
+            self.http.cassette = Some((path, CassetteMode::Replay));
+        }
+        if let Some(url) = arg_value::<String>(args, "--public-url") {
+            self.public_url = url.trim_end_matches('/').to_string();
+        }
+        if self.http.is_recording() {
+            self.http.cache_dir = None;
+        }
+        self
+    }
+}
+
+fn env_or<T: FromStr>(key: &str, default: T) -> T {
+    match std::env::var(key) {
+        Ok(v) => v.trim().parse().unwrap_or_else(|_| {
+            eprintln!("[CONFIG] {key}={v} 값을 읽지 못해 기본값을 사용합니다.");
+            default
+        }),
+        Err(_) => default,
+    }
+}
+
+fn env_string(key: &str) -> Option<String> {
+    std::env::var(key)
+        .ok()
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+}
+
+/// "1/true/yes/on" → true, "0/false/no/off" → false
+fn env_flag(key: &str, default: bool) -> bool {
+    match std::env::var(key).map(|v| v.trim().to_lowercase()) {
+        Ok(v) if matches!(v.as_str(), "1" | "true" | "yes" | "on") => true,
+        Ok(v) if matches!(v.as_str(), "0" | "false" | "no" | "off" | "") => false,
+        Ok(v) => {
+            eprintln!("[CONFIG] {key}={v} 값을 읽지 못해 기본값을 사용합니다.");
+            default
+        }
+        Err(_) => default,
+    }
+}
+
+fn arg_value<T: FromStr>(args: &[String], flag: &str) -> Option<T> {
+    let prefix = format!("{flag}=");
+    for (i, a) in args.iter().enumerate() {
+        let raw = if a == flag {
+            args.get(i + 1).map(String::as_str)
+        } else {
+            a.strip_prefix(&prefix)
+        };
+        if let Some(raw) = raw {
+            match raw.parse() {
+                Ok(v) => return Some(v),
+                Err(_) => eprintln!("[CONFIG] {flag} {raw} 값을 읽지 못해 무시합니다."),
+            }
+        }
+    }
+    None
+}
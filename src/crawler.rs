@@ -1,34 +1,34 @@
 // src/crawler.rs
-use crate::schools::{sookmyung, dongduk, seoul}; // ← 추가
+use futures::stream::{self, StreamExt};
 
-pub fn crawl(school_name: Option<&str>) -> Result<Vec<sookmyung::Notice>, Box<dyn std::error::Error>> {
-    match school_name {
-        Some("sookmyung") => {
-            println!("<<숙명여자대학교 공지사항>>");
-            let notices = sookmyung::fetch_notices()?;
-            Ok(notices)
-        }
-        Some("dongduk") => {
-            println!("<<동덕여자대학교 공지사항>>");
-            let notices = dongduk::fetch_notices()?;
-            Ok(notices)
-        }
-        Some("seoul") => {
-            println!("<<서울여자대학교 공지사항>>");
-            let notices = seoul::fetch_notices()?;
-            Ok(notices)
-        }
-        Some(other) => {
-            println!("학교 '{}'는 없습니다.", other);
-            Ok(Vec::new())
-        }
-        None => {
-            println!("<<전체 학교 공지사항>>");
-            let mut notices = Vec::new();                   // ← 변경
-            notices.extend(sookmyung::fetch_notices()?);           // ← 변경
-            notices.extend(dongduk::fetch_notices()?);             // ← 변경
-            notices.extend(seoul::fetch_notices()?);               // ← 변경
-            Ok(notices)
-        }
-    }
+use crate::schools::{self, FetchOptions, HttpClient, ListStats, Notice, School, ScrapeError};
+
+/// 학교 1곳의 수집 결과
+pub struct SchoolCrawl {
+    pub school: &'static dyn School,
+    pub result: Result<Vec<Notice>, ScrapeError>,
+    /// 헬스 리포트용 목록 통계
+    pub stats: ListStats,
+}
+
+/// 등록된 모든 학교를 최대 `limit`개씩 동시에 수집. 결과는 등록 순서대로
+pub async fn crawl_all<F>(
+    client: &HttpClient,
+    limit: usize,
+    opts_for: F,
+) -> Vec<SchoolCrawl>
+where
+    F: Fn(&dyn School) -> FetchOptions,
+{
+    stream::iter(schools::all().iter().copied())
+        .map(|school| {
+            let opts = opts_for(school);
+            async move {
+                let (result, stats) = school.fetch_with_stats(client, &opts).await;
+                SchoolCrawl { school, result, stats }
+            }
+        })
+        .buffered(limit.max(1))
+        .collect()
+        .await
 }
@@ -0,0 +1,245 @@
+// src/diff.rs
+// 수집 1회의 변화: 저장소에 있던 글과 비교해 새 글/수정된 글/그대로인 글/게시판에서 사라진 글로 분류
+// 글 1건의 수정 이력(버전)과 버전 간 비교도 여기서 만듭니다.
+//
+// 저장소(`store::Store::upsert`)가 만들고, 로그/알림/피드 쪽에서 읽습니다.
+
+use chrono::{DateTime, FixedOffset, NaiveDate};
+use similar::{ChangeTag, TextDiff};
+use std::fmt;
+
+use crate::cache::fnv1a64;
+use crate::model::{self, Attachment, Notice};
+
+/// 글 1건에서 바뀐 항목
+#[derive(Debug, Clone, PartialEq)]
+pub enum Change {
+    Title { before: String, after: String },
+    /// 게시일 (날짜만 비교)
+    Published { before: Option<NaiveDate>, after: Option<NaiveDate> },
+    Body,
+    Attachments,
+}
+
+impl Change {
+    /// 항목 이름만 ("제목", "본문" 등)
+    pub fn label(&self) -> &'static str {
+        match self {
+            Change::Title { .. } => "제목",
+            Change::Published { .. } => "게시일",
+            Change::Body => "본문",
+            Change::Attachments => "첨부",
+        }
+    }
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let date = |d: &Option<NaiveDate>| d.map_or("-".to_string(), |d| d.to_string());
+        match self {
+            Change::Title { before, after } => write!(f, "제목: {before} → {after}"),
+            Change::Published { before, after } => write!(f, "게시일: {} → {}", date(before), date(after)),
+            Change::Body => f.write_str("본문"),
+            Change::Attachments => f.write_str("첨부"),
+        }
+    }
+}
+
+/// 두 버전 사이에 바뀐 항목. 조회수/고정 여부처럼 글 내용이 아닌 것은 보지 않음
+pub fn changes(old: &Notice, new: &Notice) -> Vec<Change> {
+    Revision::of(old, 0, old.fetched_at).changes(&Revision::of(new, 0, new.fetched_at))
+}
+
+/// 글 1건의 한 시점 내용 (저장소에 수정될 때마다 1개씩 쌓임)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Revision {
+    /// 1부터 시작하는 순번
+    pub seq: usize,
+    /// 이 내용을 처음 본 시각
+    pub seen_at: DateTime<FixedOffset>,
+    pub title: String,
+    pub published: Option<DateTime<FixedOffset>>,
+    /// 본문 HTML 해시 (본문을 읽지 않았으면 None)
+    pub body_hash: Option<String>,
+    /// 비교용 본문 평문 (문단/줄바꿈마다 한 줄)
+    pub body_text: Option<String>,
+    pub attachments: Vec<Attachment>,
+}
+
+impl Revision {
+    pub fn of(n: &Notice, seq: usize, seen_at: DateTime<FixedOffset>) -> Self {
+        Revision {
+            seq,
+            seen_at,
+            title: n.title.clone(),
+            published: n.published,
+            body_hash: n.body_html.as_deref().map(|b| format!("{:016x}", fnv1a64(b))),
+            body_text: n.body_html.as_deref().map(model::html_text),
+            attachments: n.attachments.clone(),
+        }
+    }
+
+    /// 이 버전 → `next`에서 바뀐 항목
+    pub fn changes(&self, next: &Revision) -> Vec<Change> {
+        let mut out = Vec::new();
+        if self.title != next.title {
+            out.push(Change::Title {
+                before: self.title.clone(),
+                after: next.title.clone(),
+            });
+        }
+        let (before, after) = (self.published.map(|d| d.date_naive()), next.published.map(|d| d.date_naive()));
+        if before != after {
+            out.push(Change::Published { before, after });
+        }
+        if self.body_hash != next.body_hash {
+            out.push(Change::Body);
+        }
+        if self.attachments != next.attachments {
+            out.push(Change::Attachments);
+        }
+        out
+    }
+
+    /// 이 버전 → `next`의 사람이 읽는 비교. 본문은 바뀐 줄과 앞뒤 1줄만
+    pub fn render_diff(&self, next: &Revision) -> String {
+        let mut out = String::new();
+        for change in self.changes(next) {
+            match change {
+                Change::Title { before, after } => out.push_str(&format!("제목\n  - {before}\n  + {after}\n")),
+                Change::Published { .. } => out.push_str(&format!("{change}\n")),
+                Change::Body => {
+                    out.push_str("본문\n");
+                    let (old, new) = (self.body_text.as_deref(), next.body_text.as_deref());
+                    match (old, new) {
+                        (None, _) => out.push_str("  (이전 버전은 본문을 읽지 않음)\n"),
+                        (_, None) => out.push_str("  (이번 버전은 본문을 읽지 않음)\n"),
+                        (Some(old), Some(new)) => out.push_str(&line_diff(old, new)),
+                    }
+                }
+                Change::Attachments => {
+                    out.push_str("첨부\n");
+                    for a in self.attachments.iter().filter(|a| !next.attachments.contains(a)) {
+                        out.push_str(&format!("  - {}\n", a.name));
+                    }
+                    for a in next.attachments.iter().filter(|a| !self.attachments.contains(a)) {
+                        out.push_str(&format!("  + {}\n", a.name));
+                    }
+                }
+            }
+        }
+        out
+    }
+}
+
+/// 줄 단위 비교. 바뀐 부분마다 앞뒤 1줄을 함께 보여 주고, 떨어진 부분 사이는 "…"
+fn line_diff(old: &str, new: &str) -> String {
+    let diff = TextDiff::from_lines(old, new);
+    let mut out = String::new();
+    for (i, group) in diff.grouped_ops(1).iter().enumerate() {
+        if i > 0 {
+            out.push_str("  …\n");
+        }
+        for op in group {
+            for change in diff.iter_changes(op) {
+                let sign = match change.tag() {
+                    ChangeTag::Delete => '-',
+                    ChangeTag::Insert => '+',
+                    ChangeTag::Equal => ' ',
+                };
+                out.push_str(&format!("  {sign} {}\n", change.value().trim_end()));
+            }
+        }
+    }
+    out
+}
+
+/// 수정 이력 전체: 첫 버전, 그다음 버전마다 바뀐 내용
+pub fn render_history(notice: &Notice, revisions: &[Revision]) -> String {
+    let mut out = format!("{} {}\n{}\n", notice.guid(), notice.title, notice.url);
+    let stamp = |r: &Revision| r.seen_at.format("%Y-%m-%d %H:%M").to_string();
+    for (i, rev) in revisions.iter().enumerate() {
+        match i.checked_sub(1).map(|p| &revisions[p]) {
+            None => out.push_str(&format!("\nr{} {} 처음 수집\n", rev.seq, stamp(rev))),
+            Some(prev) => {
+                let labels: Vec<&str> = prev.changes(rev).iter().map(Change::label).collect();
+                out.push_str(&format!("\nr{} {} {} 수정\n", rev.seq, stamp(rev), labels.join("·")));
+                for line in prev.render_diff(rev).lines() {
+                    out.push_str(&format!("  {line}\n"));
+                }
+            }
+        }
+    }
+    if revisions.len() <= 1 {
+        out.push_str("\n수정된 적 없음\n");
+    }
+    out
+}
+
+/// 수정된 글 (`notice`는 바뀐 뒤 내용)
+#[derive(Debug, Clone)]
+pub struct Edit {
+    pub notice: Notice,
+    pub changes: Vec<Change>,
+}
+
+/// 게시판에서 사라진 글. 저장소에는 `removed_at`과 함께 남고 피드에서만 빠짐
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tombstone {
+    pub guid: String,
+    pub title: String,
+    pub url: String,
+    pub removed_at: DateTime<FixedOffset>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct CrawlDiff {
+    pub school: String,
+    /// 처음 본 글 (삭제됐다가 다시 나타난 글 포함)
+    pub new: Vec<Notice>,
+    pub edited: Vec<Edit>,
+    /// 바뀐 것이 없는 글의 GUID
+    pub unchanged: Vec<String>,
+    /// 이번에 읽은 범위(가장 오래된 일반 글의 게시일 이후)에 있어야 하는데 없는 글
+    pub removed: Vec<Tombstone>,
+}
+
+impl CrawlDiff {
+    pub fn new(school: &str) -> Self {
+        CrawlDiff {
+            school: school.to_string(),
+            ..CrawlDiff::default()
+        }
+    }
+}
+
+impl fmt::Display for CrawlDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "[DIFF] {}: 새 글 {}건, 수정 {}건, 그대로 {}건, 삭제 {}건",
+            self.school,
+            self.new.len(),
+            self.edited.len(),
+            self.unchanged.len(),
+            self.removed.len()
+        )?;
+        for n in &self.new {
+            write!(f, "\n  + {}", n.title)?;
+        }
+        for e in &self.edited {
+            let changes: Vec<String> = e.changes.iter().map(Change::to_string).collect();
+            write!(f, "\n  ~ {} ({})", e.notice.title, changes.join(", "))?;
+        }
+        for t in &self.removed {
+            write!(f, "\n  - {}", t.title)?;
+        }
+        Ok(())
+    }
+}
+
+/// 피드 설명 앞에 붙이는 한 줄 수정 표시 (예: "수정됨 (2025-02-05 14:00): 제목: A → B, 본문")
+pub fn change_note(at: DateTime<FixedOffset>, changes: &[Change]) -> String {
+    let items: Vec<String> = changes.iter().map(Change::to_string).collect();
+    format!("수정됨 ({}): {}", at.format("%Y-%m-%d %H:%M"), items.join(", "))
+}
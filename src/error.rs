@@ -0,0 +1,141 @@
+// src/error.rs
+use reqwest::StatusCode;
+use std::fmt;
+use std::time::Duration;
+
+use crate::model::Notice;
+
+/// 스크래퍼 오류. 일시적 장애(네트워크/서버)와 구조 변경을 구분해서 다룹니다.
+#[derive(Debug)]
+pub enum ScrapeError {
+    /// 연결 실패/타임아웃/본문 수신 중 끊김 등 전송 계층 오류
+    Transport {
+        school: &'static str,
+        url: String,
+        source: reqwest::Error,
+    },
+    /// 2xx가 아닌 응답
+    HttpStatus {
+        school: &'static str,
+        url: String,
+        status: StatusCode,
+    },
+    /// 응답 본문 디코딩 실패 (본문을 받다 끊긴 것은 `Transport`)
+    Decode {
+        school: &'static str,
+        url: String,
+        source: reqwest::Error,
+    },
+    /// 목록 행을 하나도 찾지 못함 — 페이지 구조가 바뀐 것으로 추정
+    NoRows { school: &'static str, url: String },
+    /// 행은 있지만 한 건도 읽지 못함 (첫 실패 행 번호와 이유)
+    RowParse {
+        school: &'static str,
+        row: usize,
+        reason: String,
+    },
+    /// robots.txt가 수집을 금지한 주소라 요청하지 않음
+    RobotsDisallowed { school: &'static str, url: String },
+    /// robots.txt를 재시도까지 해도 받지 못해(5xx/네트워크 오류) 요청하지 않음 (RFC 9309 2.3.1.4)
+    RobotsUnreachable {
+        school: &'static str,
+        url: String,
+        source: Box<ScrapeError>,
+    },
+    /// 연속 실패로 회로 차단기가 열려 요청을 보내지 않음
+    CircuitOpen {
+        school: &'static str,
+        retry_in: Duration,
+    },
+}
+
+impl ScrapeError {
+    pub fn school(&self) -> &'static str {
+        match self {
+            ScrapeError::Transport { school, .. }
+            | ScrapeError::HttpStatus { school, .. }
+            | ScrapeError::Decode { school, .. }
+            | ScrapeError::NoRows { school, .. }
+            | ScrapeError::RowParse { school, .. }
+            | ScrapeError::RobotsDisallowed { school, .. }
+            | ScrapeError::RobotsUnreachable { school, .. }
+            | ScrapeError::CircuitOpen { school, .. } => school,
+        }
+    }
+
+    /// 잠시 후 다시 시도하면 나아질 수 있는 오류인지 (네트워크, 5xx, 429, 차단 중, robots.txt 못 받음)
+    pub fn is_transient(&self) -> bool {
+        match self {
+            ScrapeError::Transport { .. }
+            | ScrapeError::CircuitOpen { .. }
+            | ScrapeError::RobotsUnreachable { .. } => true,
+            ScrapeError::HttpStatus { status, .. } => {
+                status.is_server_error() || *status == StatusCode::TOO_MANY_REQUESTS
+            }
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for ScrapeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScrapeError::Transport { school, url, source } => {
+                write!(f, "[{school}] 요청 실패 ({url}): {source}")
+            }
+            ScrapeError::HttpStatus { school, url, status } => {
+                write!(f, "[{school}] HTTP {status} ({url})")
+            }
+            ScrapeError::Decode { school, url, source } => {
+                write!(f, "[{school}] 응답 본문 읽기 실패 ({url}): {source}")
+            }
+            ScrapeError::NoRows { school, url } => {
+                write!(f, "[{school}] 목록 행 0건 — 페이지 구조를 다시 확인하세요 ({url})")
+            }
+            ScrapeError::RowParse { school, row, reason } => {
+                write!(f, "[{school}] {row}번째 행 파싱 실패: {reason}")
+            }
+            ScrapeError::RobotsDisallowed { school, url } => {
+                write!(f, "[{school}] robots.txt가 수집을 금지한 주소 ({url})")
+            }
+            ScrapeError::RobotsUnreachable { school, url, source } => {
+                write!(f, "[{school}] robots.txt를 받지 못해 요청하지 않음 ({url}): {source}")
+            }
+            ScrapeError::CircuitOpen { school, retry_in } => {
+                write!(f, "[{school}] 연속 실패로 요청 차단 중 ({}초 후 재개)", retry_in.as_secs())
+            }
+        }
+    }
+}
+
+impl std::error::Error for ScrapeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ScrapeError::Transport { source, .. } | ScrapeError::Decode { source, .. } => {
+                Some(source)
+            }
+            ScrapeError::RobotsUnreachable { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+/// 목록 파싱 결과 점검: 행이 없으면 `NoRows`, 행은 있는데 한 건도 못 읽었으면 첫 `RowParse`
+pub fn check_parsed(
+    school: &'static str,
+    url: &str,
+    rows: usize,
+    first_failure: Option<(usize, String)>,
+    notices: Vec<Notice>,
+) -> Result<Vec<Notice>, ScrapeError> {
+    if !notices.is_empty() {
+        return Ok(notices);
+    }
+    match first_failure {
+        Some((row, reason)) if rows > 0 => Err(ScrapeError::RowParse { school, row, reason }),
+        _ => Err(ScrapeError::NoRows {
+            school,
+            url: url.to_string(),
+        }),
+    }
+}
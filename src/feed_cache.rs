@@ -0,0 +1,192 @@
+// src/feed_cache.rs
+// HTTP 서버의 학교별 피드 캐시: 요청마다 학교 사이트를 긁지 않고 만들어 둔 피드(RSS/Atom/JSON)를 돌려줌
+//
+// TTL 안이면 그대로(HIT), 지났으면 일단 지난 피드를 주고 뒤에서 새로 수집(STALE, stale-while-revalidate).
+// 새로 수집하다 실패하면 마지막으로 성공한 피드를 계속 주되 오류를 기록해 두고 경고 헤더를 붙입니다.
+// 실제 수집/렌더링은 main.rs가 하고, 여기서는 상태만 관리합니다. 캐시 키는 학교 + 고정 글 모드 + 형식이고,
+// 같은 학교의 피드는 한 번 수집으로 함께 갱신됩니다.
+
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::model::PinnedMode;
+
+/// 같은 공지 목록으로 만드는 피드 형식
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FeedFormat {
+    Rss,
+    Atom,
+    /// JSON Feed 1.1
+    Json,
+}
+
+impl FeedFormat {
+    /// `public/school-rss/{school}/` 아래 파일 이름이자 서버 경로의 마지막 부분
+    pub fn file_name(self) -> &'static str {
+        match self {
+            FeedFormat::Rss => "rss.xml",
+            FeedFormat::Atom => "atom.xml",
+            FeedFormat::Json => "feed.json",
+        }
+    }
+
+    pub fn from_file_name(name: &str) -> Option<Self> {
+        [FeedFormat::Rss, FeedFormat::Atom, FeedFormat::Json].into_iter().find(|f| f.file_name() == name)
+    }
+
+    pub fn content_type(self) -> &'static str {
+        match self {
+            FeedFormat::Rss => "application/rss+xml; charset=utf-8",
+            FeedFormat::Atom => "application/atom+xml; charset=utf-8",
+            FeedFormat::Json => "application/feed+json; charset=utf-8",
+        }
+    }
+}
+
+/// 캐시된 피드 1개
+#[derive(Debug, Clone)]
+pub struct CachedFeed {
+    /// 피드 본문 (RSS/Atom XML 또는 JSON)
+    pub xml: String,
+    /// 이 피드를 만든(마지막으로 수집에 성공한) 시각
+    pub built_at: Instant,
+    /// 마지막으로 수집을 시도한 시각. TTL은 이 시각 기준
+    pub checked_at: Instant,
+    /// 마지막 수집이 실패했으면 그 오류 (다음 성공 때 지워짐)
+    pub last_error: Option<String>,
+}
+
+/// 요청 1건의 캐시 조회 결과
+#[derive(Debug, Clone)]
+pub enum Lookup {
+    Fresh(CachedFeed),
+    /// TTL이 지남. 지난 피드를 주고 뒤에서 새로 수집
+    Stale(CachedFeed),
+    Missing,
+}
+
+/// 헬스 체크/로그용 캐시 상태
+#[derive(Debug, Clone, Serialize)]
+pub struct FeedCacheStatus {
+    pub school: &'static str,
+    pub pinned: String,
+    pub file: &'static str,
+    /// 피드를 만든 지 지난 시간(초)
+    pub age_secs: u64,
+    pub stale: bool,
+    pub last_error: Option<String>,
+}
+
+pub struct FeedCache {
+    ttl: Duration,
+    entries: Mutex<HashMap<(&'static str, PinnedMode, FeedFormat), CachedFeed>>,
+    /// 지금 뒤에서 새로 수집 중인 학교
+    refreshing: Mutex<HashSet<&'static str>>,
+}
+
+impl FeedCache {
+    /// `ttl`이 0이면 캐시하지 않음 (요청마다 수집)
+    pub fn new(ttl: Duration) -> Self {
+        FeedCache {
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+            refreshing: Mutex::new(HashSet::new()),
+        }
+    }
+
+    pub fn enabled(&self) -> bool {
+        !self.ttl.is_zero()
+    }
+
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    pub fn get(&self, school: &'static str, pinned: PinnedMode, format: FeedFormat) -> Lookup {
+        if !self.enabled() {
+            return Lookup::Missing;
+        }
+        match self.entries.lock().unwrap().get(&(school, pinned, format)) {
+            None => Lookup::Missing,
+            Some(feed) if feed.checked_at.elapsed() < self.ttl => Lookup::Fresh(feed.clone()),
+            Some(feed) => Lookup::Stale(feed.clone()),
+        }
+    }
+
+    /// 수집 성공: 새 피드로 바꾸고 오류 기록을 지움
+    pub fn put(&self, school: &'static str, pinned: PinnedMode, format: FeedFormat, xml: String) {
+        if !self.enabled() {
+            return;
+        }
+        let now = Instant::now();
+        let feed = CachedFeed {
+            xml,
+            built_at: now,
+            checked_at: now,
+            last_error: None,
+        };
+        self.entries.lock().unwrap().insert((school, pinned, format), feed);
+    }
+
+    /// 수집 실패: 이 학교의 피드는 그대로 두고 오류만 기록. 다음 시도는 TTL 뒤
+    pub fn fail(&self, school: &'static str, error: &str) {
+        let now = Instant::now();
+        for ((key, _, _), feed) in self.entries.lock().unwrap().iter_mut() {
+            if *key == school {
+                feed.checked_at = now;
+                feed.last_error = Some(error.to_string());
+            }
+        }
+    }
+
+    /// 이 학교에 캐시된 (고정 글 모드, 형식)들 (새로 수집하면 모두 다시 만듦)
+    pub fn variants(&self, school: &'static str) -> Vec<(PinnedMode, FeedFormat)> {
+        let entries = self.entries.lock().unwrap();
+        entries
+            .keys()
+            .filter(|(key, _, _)| *key == school)
+            .map(|(_, pinned, format)| (*pinned, *format))
+            .collect()
+    }
+
+    /// 마지막 시도가 `age`보다 오래된 피드가 있는 학교 (백그라운드 갱신 대상)
+    pub fn due(&self, age: Duration) -> Vec<&'static str> {
+        let entries = self.entries.lock().unwrap();
+        let mut schools: Vec<&'static str> = entries
+            .iter()
+            .filter(|(_, feed)| feed.checked_at.elapsed() >= age)
+            .map(|((school, _, _), _)| *school)
+            .collect();
+        schools.sort_unstable();
+        schools.dedup();
+        schools
+    }
+
+    /// 뒤에서 새로 수집을 시작해도 되는지. 이미 누가 수집 중이면 false
+    pub fn begin_refresh(&self, school: &'static str) -> bool {
+        self.refreshing.lock().unwrap().insert(school)
+    }
+
+    pub fn end_refresh(&self, school: &'static str) {
+        self.refreshing.lock().unwrap().remove(school);
+    }
+
+    pub fn snapshot(&self) -> Vec<FeedCacheStatus> {
+        let entries = self.entries.lock().unwrap();
+        let mut out: Vec<FeedCacheStatus> = entries
+            .iter()
+            .map(|((school, pinned, format), feed)| FeedCacheStatus {
+                school,
+                pinned: pinned.to_string(),
+                file: format.file_name(),
+                age_secs: feed.built_at.elapsed().as_secs(),
+                stale: feed.checked_at.elapsed() >= self.ttl,
+                last_error: feed.last_error.clone(),
+            })
+            .collect();
+        out.sort_by(|a, b| (a.school, &a.pinned, a.file).cmp(&(b.school, &b.pinned, b.file)));
+        out
+    }
+}
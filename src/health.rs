@@ -0,0 +1,244 @@
+// src/health.rs
+// 스크래퍼 헬스 리포트: 목록 페이지가 얼마나 잘 읽혔는지, 구조가 지난 정상 실행과 같은지 점검
+//
+// 학교 사이트가 개편되면 행 0건/빈 제목/날짜 N/A/javascript: 링크가 늘어납니다. 이럴 때 조용히
+// 빈 피드를 배포하지 않고, 무엇이 바뀌었는지 알려 주고 실패하는 것이 목적입니다.
+
+use select::node::Node;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::error::ScrapeError;
+use crate::model::{now_kst, Notice};
+
+/// 목록 파싱 중에 모으는 통계 (모든 페이지 합계, 구조 지문은 1페이지 기준)
+#[derive(Debug, Clone, Default)]
+pub struct ListStats {
+    /// 목록 행 요소 수 (읽지 못한 행 포함)
+    pub rows: usize,
+    pub missing_title: usize,
+    pub missing_url: usize,
+    /// 1페이지 목록 컨테이너의 구조 지문. 컨테이너가 없으면 None
+    pub fingerprint: Option<String>,
+    /// 읽은 목록 페이지 수
+    pub pages: usize,
+}
+
+impl ListStats {
+    /// 목록 페이지 1장 시작. `container`는 학교별 목록 컨테이너 (table tbody, ul.board-basic 등)
+    pub fn begin_page(&mut self, container: Option<Node>) {
+        if self.pages == 0 {
+            self.fingerprint = container.map(|c| fingerprint(&c));
+        }
+        self.pages += 1;
+    }
+}
+
+/// 목록 컨테이너의 구조 지문: 컨테이너 태그 + 마지막 행의 "행>칸[칸 안의 태그들]" 모양을 해시.
+/// 상단 고정 글은 모양이 조금 다를 수 있어 맨 아래(가장 오래된 일반 글) 행만 봄.
+/// 글 내용/개수/class가 바뀌어도 같고, 마크업 구조가 바뀌면 달라짐
+pub fn fingerprint(container: &Node) -> String {
+    let row_shape = element_children(container)
+        .last()
+        .map(|row| {
+            let cells: Vec<String> = element_children(&row)
+                .map(|cell| {
+                    let inner: Vec<&str> = element_children(&cell).filter_map(|n| n.name()).collect();
+                    format!("{}[{}]", cell.name().unwrap_or(""), inner.join(","))
+                })
+                .collect();
+            format!("{}>{}", row.name().unwrap_or(""), cells.join(","))
+        })
+        .unwrap_or_default();
+    let signature = format!("{}|{row_shape}", container.name().unwrap_or(""));
+    format!("{:08x}", fnv1a32(&signature))
+}
+
+fn element_children<'a>(node: &Node<'a>) -> impl Iterator<Item = Node<'a>> {
+    node.children().filter(|n| n.name().is_some())
+}
+
+fn fnv1a32(s: &str) -> u32 {
+    s.bytes().fold(0x811c9dc5, |h, b| (h ^ b as u32).wrapping_mul(0x01000193))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HealthStatus {
+    Ok,
+    /// 일부 행만 이상하거나 구조 지문만 바뀜 — 피드는 배포
+    Warn,
+    /// 사이트 개편 의심 — 피드를 배포하지 않음
+    Broken,
+}
+
+/// 지난 정상 실행 기록 (`<state_dir>/health/<학교>.json`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Baseline {
+    pub fingerprint: Option<String>,
+    pub rows: usize,
+    pub recorded_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HealthReport {
+    pub school: String,
+    pub status: HealthStatus,
+    pub rows: usize,
+    pub parsed: usize,
+    pub missing_title: usize,
+    pub missing_date: usize,
+    pub missing_url: usize,
+    pub javascript_urls: usize,
+    pub fingerprint: Option<String>,
+    pub baseline: Option<Baseline>,
+    /// 사람이 읽는 진단 (심각한 것부터)
+    pub problems: Vec<String>,
+}
+
+impl HealthReport {
+    /// 수집 결과 + 목록 통계 + 지난 정상 기록으로 리포트 작성.
+    /// 일시적 오류(네트워크 등)는 구조 문제가 아니므로 호출하는 쪽에서 걸러 냄
+    pub fn evaluate(
+        school: &str,
+        stats: &ListStats,
+        result: Result<&[Notice], &ScrapeError>,
+        baseline: Option<Baseline>,
+    ) -> Self {
+        let notices = result.unwrap_or_default();
+        let parsed = notices.len();
+        let missing_date = notices.iter().filter(|n| n.published.is_none()).count();
+        let javascript_urls = notices.iter().filter(|n| n.url.starts_with("javascript:")).count();
+        let mut broken = Vec::new();
+        let mut warn = Vec::new();
+
+        if let Err(e) = result {
+            broken.push(e.to_string());
+        }
+        if stats.pages > 0 && stats.fingerprint.is_none() {
+            broken.push("목록 컨테이너를 찾지 못했습니다.".to_string());
+        }
+        let unreadable = stats.missing_title + stats.missing_url;
+        if stats.rows > 0 && unreadable * 2 > stats.rows {
+            broken.push(format!("행 {}개 중 {unreadable}개에서 제목/링크를 읽지 못했습니다.", stats.rows));
+        } else if unreadable > 0 {
+            warn.push(format!("행 {}개 중 {unreadable}개에서 제목/링크를 읽지 못했습니다.", stats.rows));
+        }
+        if parsed > 0 && missing_date == parsed {
+            broken.push("모든 글에서 날짜를 읽지 못했습니다.".to_string());
+        } else if missing_date > 0 {
+            warn.push(format!("{missing_date}개 글의 날짜를 읽지 못했습니다."));
+        }
+        if parsed > 0 && javascript_urls == parsed {
+            broken.push("모든 글의 링크가 javascript: 폴백입니다.".to_string());
+        } else if javascript_urls > 0 {
+            warn.push(format!("{javascript_urls}개 글의 링크가 javascript: 폴백입니다."));
+        }
+
+        if let Some(base) = &baseline {
+            if base.fingerprint.is_some() && stats.fingerprint.is_some() && base.fingerprint != stats.fingerprint {
+                let msg = format!(
+                    "목록 구조가 지난 정상 실행({})과 다릅니다: {} → {}",
+                    base.recorded_at,
+                    base.fingerprint.as_deref().unwrap_or("-"),
+                    stats.fingerprint.as_deref().unwrap_or("-")
+                );
+                if broken.is_empty() {
+                    warn.push(msg);
+                } else {
+                    // 파싱 실패와 함께 구조가 바뀌었으면 개편으로 보고 맨 앞에
+                    broken.insert(0, format!("사이트 개편 의심 — {msg}"));
+                }
+            }
+            if base.rows >= 4 && stats.rows * 2 < base.rows && broken.is_empty() {
+                warn.push(format!("목록 행 수가 {}개에서 {}개로 줄었습니다.", base.rows, stats.rows));
+            }
+        }
+
+        let status = if !broken.is_empty() {
+            HealthStatus::Broken
+        } else if !warn.is_empty() {
+            HealthStatus::Warn
+        } else {
+            HealthStatus::Ok
+        };
+        broken.extend(warn);
+        HealthReport {
+            school: school.to_string(),
+            status,
+            rows: stats.rows,
+            parsed,
+            missing_title: stats.missing_title,
+            missing_date,
+            missing_url: stats.missing_url,
+            javascript_urls,
+            fingerprint: stats.fingerprint.clone(),
+            baseline,
+            problems: broken,
+        }
+    }
+
+    pub fn is_broken(&self) -> bool {
+        self.status == HealthStatus::Broken
+    }
+
+    /// 다음 실행의 비교 기준으로 남길 기록
+    pub fn to_baseline(&self) -> Baseline {
+        Baseline {
+            fingerprint: self.fingerprint.clone(),
+            rows: self.rows,
+            recorded_at: now_kst().format("%Y-%m-%d %H:%M").to_string(),
+        }
+    }
+}
+
+impl fmt::Display for HealthReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self.status {
+            HealthStatus::Ok => "정상",
+            HealthStatus::Warn => "주의",
+            HealthStatus::Broken => "구조 변경 의심",
+        };
+        write!(
+            f,
+            "[HEALTH] {}: {label} — 행 {}, 파싱 {}, 제목 누락 {}, 링크 누락 {}, 날짜 누락 {}, javascript: {}, 구조 {}",
+            self.school,
+            self.rows,
+            self.parsed,
+            self.missing_title,
+            self.missing_url,
+            self.missing_date,
+            self.javascript_urls,
+            self.fingerprint.as_deref().unwrap_or("-")
+        )?;
+        for p in &self.problems {
+            write!(f, "\n  - {p}")?;
+        }
+        Ok(())
+    }
+}
+
+fn baseline_path(state_dir: &Path, school: &str) -> PathBuf {
+    state_dir.join("health").join(format!("{school}.json"))
+}
+
+/// 지난 정상 실행 기록 읽기 (없으면 None — 첫 실행)
+pub fn load_baseline(state_dir: &Path, school: &str) -> Option<Baseline> {
+    let raw = fs::read_to_string(baseline_path(state_dir, school)).ok()?;
+    serde_json::from_str(&raw).ok()
+}
+
+/// 깨지지 않은 실행만 다음 비교 기준으로 저장
+pub fn save_baseline(state_dir: &Path, report: &HealthReport) -> std::io::Result<()> {
+    if report.is_broken() {
+        return Ok(());
+    }
+    let path = baseline_path(state_dir, &report.school);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let json = serde_json::to_string_pretty(&report.to_baseline()).map_err(std::io::Error::other)?;
+    fs::write(path, json)
+}
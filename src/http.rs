@@ -0,0 +1,318 @@
+// src/http.rs
+// 모든 스크래퍼가 함께 쓰는 HTTP 클라이언트 (연결 풀 공유)
+
+use reqwest::header::{
+    HeaderMap, HeaderName, HeaderValue, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED,
+    REFERER, RETRY_AFTER,
+};
+use reqwest::{Client, Proxy, StatusCode, Url};
+use std::io;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::breaker::CircuitBreakers;
+use crate::cache::{CachedPage, HttpCache};
+use crate::config::HttpConfig;
+use crate::error::ScrapeError;
+use crate::model::now_kst;
+use crate::polite::{Politeness, Robots, ROBOTS_AGENT};
+use crate::transport::{Cassette, ReqwestTransport, Transport, TransportRequest};
+
+/// 재시도 대기 시간 상한 (Retry-After 포함)
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+#[derive(Clone)]
+pub struct HttpClient {
+    transport: Arc<dyn Transport>,
+    max_retries: u32,
+    retry_base: Duration,
+    breakers: Arc<CircuitBreakers>,
+    polite: Arc<Politeness>,
+    cache: Arc<HttpCache>,
+}
+
+/// 요청 1번의 결과
+enum Fetched {
+    Body {
+        text: String,
+        etag: Option<String>,
+        last_modified: Option<String>,
+    },
+    /// 304 — 캐시 본문 그대로
+    NotModified,
+}
+
+impl HttpClient {
+    /// 설정으로 클라이언트 생성. 잘못된 헤더는 경고 후 건너뜀.
+    /// 카세트가 설정돼 있으면 그 파일로 녹화/재생
+    pub fn new(config: &HttpConfig) -> io::Result<Self> {
+        let mut headers = HeaderMap::new();
+        for (name, value) in &config.headers {
+            match (
+                HeaderName::from_bytes(name.as_bytes()),
+                HeaderValue::from_str(value),
+            ) {
+                (Ok(n), Ok(v)) => {
+                    headers.insert(n, v);
+                }
+                _ => eprintln!("[HTTP] 잘못된 헤더 무시: {name}: {value}"),
+            }
+        }
+
+        let mut builder = Client::builder()
+            .user_agent(config.user_agent())
+            .timeout(config.timeout)
+            .connect_timeout(config.connect_timeout)
+            .pool_max_idle_per_host(config.pool_max_idle_per_host)
+            .default_headers(headers);
+        if let Some(proxy) = &config.proxy {
+            builder = builder.proxy(Proxy::all(proxy).map_err(io::Error::other)?);
+        }
+        let live: Arc<dyn Transport> =
+            Arc::new(ReqwestTransport::new(builder.build().map_err(io::Error::other)?));
+        let transport: Arc<dyn Transport> = match &config.cassette {
+            Some((path, mode)) => {
+                eprintln!("[CASSETTE] {mode}: {}", path.display());
+                Arc::new(Cassette::open(path, *mode, live)?)
+            }
+            None => live,
+        };
+        Ok(HttpClient::with_transport(config, transport))
+    }
+
+    /// 전송 계층을 직접 지정 (테스트에서 카세트 재생 등)
+    pub fn with_transport(config: &HttpConfig, transport: Arc<dyn Transport>) -> Self {
+        HttpClient {
+            transport,
+            max_retries: config.max_retries,
+            retry_base: config.retry_base,
+            breakers: Arc::new(CircuitBreakers::new(
+                config.breaker_threshold,
+                config.breaker_cooldown,
+            )),
+            polite: Arc::new(Politeness::new(config)),
+            cache: Arc::new(HttpCache::new(config.cache_dir.clone())),
+        }
+    }
+
+    /// 학교별 회로 차단기 (헬스 체크 출력용)
+    pub fn breakers(&self) -> &CircuitBreakers {
+        &self.breakers
+    }
+
+    /// robots.txt/요청 간격 처리 (실행 요약 출력용)
+    pub fn politeness(&self) -> &Politeness {
+        &self.polite
+    }
+
+    /// 목록 페이지 조건부 요청 캐시 (실행 요약 출력용)
+    pub fn cache(&self) -> &HttpCache {
+        &self.cache
+    }
+
+    /// GET 후 2xx 확인, 본문 텍스트 반환 (상세 페이지 등 캐시하지 않는 요청)
+    pub async fn get_html(
+        &self,
+        school: &'static str,
+        url: &str,
+        referer: &str,
+    ) -> Result<String, ScrapeError> {
+        match self.fetch(school, url, referer, None).await? {
+            Fetched::Body { text, .. } => Ok(text),
+            // 검증자를 보내지 않았으므로 304가 올 일은 없음
+            Fetched::NotModified => Err(ScrapeError::HttpStatus {
+                school,
+                url: url.to_string(),
+                status: StatusCode::NOT_MODIFIED,
+            }),
+        }
+    }
+
+    /// 게시판 목록 페이지용 GET. 지난번 ETag/Last-Modified로 조건부 요청을 보내고,
+    /// 304면 디스크 캐시의 본문을 돌려줌
+    pub async fn get_list_html(
+        &self,
+        school: &'static str,
+        url: &str,
+        referer: &str,
+    ) -> Result<String, ScrapeError> {
+        if !self.cache.enabled() {
+            return self.get_html(school, url, referer).await;
+        }
+        let cached = self.cache.load(url);
+        match self.fetch(school, url, referer, cached.as_ref()).await? {
+            Fetched::NotModified => match cached {
+                Some(page) => {
+                    self.cache.record_hit();
+                    Ok(page.body)
+                }
+                None => Err(ScrapeError::HttpStatus {
+                    school,
+                    url: url.to_string(),
+                    status: StatusCode::NOT_MODIFIED,
+                }),
+            },
+            Fetched::Body { text, etag, last_modified } => {
+                self.cache.record_miss();
+                self.cache.store(&CachedPage {
+                    url: url.to_string(),
+                    etag,
+                    last_modified,
+                    body: text.clone(),
+                    stored_at: now_kst().to_rfc3339(),
+                });
+                Ok(text)
+            }
+        }
+    }
+
+    /// 요청 공통 경로: 차단기 → robots.txt → (호스트 간격 + 요청) 재시도 루프
+    async fn fetch(
+        &self,
+        school: &'static str,
+        url: &str,
+        referer: &str,
+        cached: Option<&CachedPage>,
+    ) -> Result<Fetched, ScrapeError> {
+        if let Err(retry_in) = self.breakers.check(school) {
+            return Err(ScrapeError::CircuitOpen { school, retry_in });
+        }
+        let parsed = Url::parse(url).ok();
+        if let Some(parsed) = &parsed {
+            let allowed = self
+                .polite
+                .allowed(parsed, |robots_url| self.fetch_robots(school, robots_url))
+                .await?;
+            if !allowed {
+                self.breakers.release(school);
+                return Err(ScrapeError::RobotsDisallowed {
+                    school,
+                    url: url.to_string(),
+                });
+            }
+        }
+        self.fetch_with_retries(school, url, parsed.as_ref(), referer, cached).await
+    }
+
+    /// robots.txt 받기 (페이지와 같은 재시도/차단기 경로). 없으면(4xx) 전부 허용,
+    /// 재시도해도 서버 오류(5xx)나 네트워크 오류면 일시적 오류 `RobotsUnreachable` (RFC 9309 2.3.1.3/2.3.1.4)
+    async fn fetch_robots(&self, school: &'static str, robots_url: Url) -> Result<Robots, ScrapeError> {
+        let url = robots_url.as_str();
+        match self.fetch_with_retries(school, url, Some(&robots_url), "", None).await {
+            Ok(Fetched::Body { text, .. }) => Ok(Robots::parse(&text, ROBOTS_AGENT)),
+            // 검증자를 보내지 않았으므로 304가 올 일은 없음
+            Ok(Fetched::NotModified) => Ok(Robots::default()),
+            Err(ScrapeError::HttpStatus { status, .. }) if status.is_client_error() => {
+                eprintln!("[POLITE] robots.txt 없음({status}) — 제한 없이 진행합니다: {url}");
+                Ok(Robots::default())
+            }
+            Err(e) => {
+                eprintln!("[POLITE] robots.txt를 받지 못해 이 호스트에 요청하지 않습니다. ({e})");
+                Err(ScrapeError::RobotsUnreachable {
+                    school,
+                    url: url.to_string(),
+                    source: Box::new(e),
+                })
+            }
+        }
+    }
+
+    /// (호스트 간격 + 요청) 재시도 루프. 일시적 오류는 지수 백오프(+지터)로 최대 `max_retries`번
+    /// 다시 시도하고, 그래도 실패하면 학교 차단기에 실패 1건으로 기록합니다.
+    async fn fetch_with_retries(
+        &self,
+        school: &'static str,
+        url: &str,
+        parsed: Option<&Url>,
+        referer: &str,
+        cached: Option<&CachedPage>,
+    ) -> Result<Fetched, ScrapeError> {
+        let mut attempt = 0;
+        loop {
+            // 호스트 동시 요청 슬롯 + 요청 간격. 재시도도 같은 규칙을 따름
+            let permit = match parsed {
+                Some(parsed) => self.polite.acquire(parsed).await,
+                None => None,
+            };
+            let (result, retry_after) = self.get_once(school, url, referer, cached).await;
+            drop(permit);
+            match result {
+                Err(e) if e.is_transient() && attempt < self.max_retries => {
+                    attempt += 1;
+                    let delay = retry_after
+                        .unwrap_or_else(|| backoff(self.retry_base, attempt))
+                        .min(MAX_BACKOFF);
+                    eprintln!(
+                        "[RETRY] {e} — {:.1}초 후 다시 시도 ({attempt}/{})",
+                        delay.as_secs_f32(),
+                        self.max_retries
+                    );
+                    tokio::time::sleep(delay).await;
+                }
+                Err(e) if e.is_transient() => {
+                    self.breakers.record_failure(school, &e.to_string());
+                    return Err(e);
+                }
+                // 404/디코딩 실패 등은 사이트가 응답은 한 것이므로 차단 대상이 아님
+                other => {
+                    self.breakers.record_success(school);
+                    return other;
+                }
+            }
+        }
+    }
+
+    /// 요청 1번. 429/503의 `Retry-After`(초)가 있으면 함께 반환
+    async fn get_once(
+        &self,
+        school: &'static str,
+        url: &str,
+        referer: &str,
+        cached: Option<&CachedPage>,
+    ) -> (Result<Fetched, ScrapeError>, Option<Duration>) {
+        let mut headers = HeaderMap::new();
+        // robots.txt 요청은 Referer 없이 보냄
+        if let Some(v) = HeaderValue::from_str(referer).ok().filter(|_| !referer.is_empty()) {
+            headers.insert(REFERER, v);
+        }
+        if let Some(page) = cached {
+            for (name, value) in [(IF_NONE_MATCH, &page.etag), (IF_MODIFIED_SINCE, &page.last_modified)] {
+                if let Some(v) = value.as_deref().and_then(|v| HeaderValue::from_str(v).ok()) {
+                    headers.insert(name, v);
+                }
+            }
+        }
+        let res = match self.transport.get(TransportRequest { school, url, headers }).await {
+            Ok(res) => res,
+            Err(e) => return (Err(e), None),
+        };
+        if res.status == StatusCode::NOT_MODIFIED && cached.is_some() {
+            return (Ok(Fetched::NotModified), None);
+        }
+        if !res.status.is_success() {
+            let retry_after = res
+                .header(RETRY_AFTER)
+                .and_then(|v| v.trim().parse().ok())
+                .map(Duration::from_secs);
+            let err = ScrapeError::HttpStatus {
+                school,
+                url: url.to_string(),
+                status: res.status,
+            };
+            return (Err(err), retry_after);
+        }
+        let fetched = Fetched::Body {
+            etag: res.header(ETAG),
+            last_modified: res.header(LAST_MODIFIED),
+            text: res.body,
+        };
+        (Ok(fetched), None)
+    }
+}
+
+/// `base * 2^(attempt-1)`의 절반 + 무작위 절반 (재시도가 같은 순간에 몰리지 않게)
+fn backoff(base: Duration, attempt: u32) -> Duration {
+    let ceiling = base.saturating_mul(1 << (attempt - 1).min(16)).min(MAX_BACKOFF);
+    let millis = ceiling.as_millis() as u64;
+    Duration::from_millis(millis / 2 + fastrand::u64(0..=millis / 2))
+}
@@ -0,0 +1,116 @@
+// src/json_feed.rs
+// JSON Feed 1.1 (https://jsonfeed.org/version/1.1) — 브라우저 위젯처럼 XML을 읽기 번거로운 곳용
+//
+// RSS/Atom과 같은 공지 목록으로 만듭니다. 글 id는 RSS guid와 같은 GUID("seoul:506633")입니다.
+
+use serde::Serialize;
+
+use crate::model::{self, Notice};
+use crate::schools::ChannelMeta;
+
+pub const VERSION: &str = "https://jsonfeed.org/version/1.1";
+
+#[derive(Debug, Clone, Serialize)]
+pub struct JsonFeed {
+    pub version: &'static str,
+    pub title: String,
+    pub home_page_url: String,
+    /// 이 피드 파일 자신의 주소
+    pub feed_url: String,
+    pub description: String,
+    pub language: &'static str,
+    pub authors: Vec<JsonAuthor>,
+    pub items: Vec<JsonItem>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct JsonAuthor {
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct JsonItem {
+    pub id: String,
+    pub url: String,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_html: Option<String>,
+    /// 본문 평문. 본문이 없으면 요약, 그것도 없으면 제목 (content_html/content_text 중 하나는 필수)
+    pub content_text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    /// RFC 3339
+    pub date_published: String,
+    /// 처음 본 뒤 내용이 바뀐 글만
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_modified: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub authors: Vec<JsonAuthor>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub attachments: Vec<JsonAttachment>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct JsonAttachment {
+    pub url: String,
+    pub mime_type: String,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size_in_bytes: Option<u64>,
+}
+
+impl JsonItem {
+    pub fn from_notice(n: &Notice) -> Self {
+        let content_text = match (&n.body_html, &n.summary) {
+            (Some(html), _) => model::html_text(html),
+            (None, Some(summary)) => summary.clone(),
+            (None, None) => n.title.clone(),
+        };
+        let (published, updated) = (n.pub_date(), n.updated());
+        JsonItem {
+            id: n.guid(),
+            url: n.url.clone(),
+            title: n.title.clone(),
+            content_html: n.body_html.clone(),
+            content_text,
+            summary: n.summary.clone(),
+            date_published: published.to_rfc3339(),
+            date_modified: (updated != published).then(|| updated.to_rfc3339()),
+            authors: n.author.iter().map(|a| JsonAuthor { name: a.clone() }).collect(),
+            tags: n.category.iter().cloned().collect(),
+            attachments: n
+                .attachments
+                .iter()
+                .map(|a| JsonAttachment {
+                    url: a.url.clone(),
+                    mime_type: a.mime.clone().unwrap_or_else(|| "application/octet-stream".into()),
+                    title: a.name.clone(),
+                    size_in_bytes: a.size,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// 공통 JSON Feed 생성. 피드 작성자는 학교 이름
+pub fn build_json_feed(meta: &ChannelMeta, author: &str, feed_url: &str, notices: &[Notice]) -> JsonFeed {
+    JsonFeed {
+        version: VERSION,
+        title: meta.title.to_string(),
+        home_page_url: meta.link.to_string(),
+        feed_url: feed_url.to_string(),
+        description: meta.description.to_string(),
+        language: "ko",
+        authors: vec![JsonAuthor { name: author.to_string() }],
+        items: notices.iter().map(JsonItem::from_notice).collect(),
+    }
+}
+
+impl JsonFeed {
+    /// 들여쓰기한 JSON 문자열
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("JSON Feed 직렬화")
+    }
+}
@@ -1,3 +1,20 @@
 // src/lib.rs
+pub mod breaker;
+pub mod cache;
+pub mod config;
 pub mod crawler;
-pub mod schools;
\ No newline at end of file
+pub mod diff;
+pub mod error;
+pub mod feed_cache;
+pub mod health;
+pub mod http;
+pub mod json_feed;
+pub mod model;
+pub mod polite;
+pub mod schools;
+pub mod search;
+pub mod singleflight;
+pub mod storage;
+pub mod store;
+pub mod summary;
+pub mod transport;
@@ -1,58 +1,145 @@
 // src/main.rs
-mod crawler;
-mod schools;
-mod storage;
-
-use actix_web::{web, App, HttpResponse, HttpServer, Responder};
-use schools::{dongduk, seoul, sookmyung};
-use std::io::{Error as IoError, ErrorKind};
-
-// 날짜/URL 정규화
-use chrono::{Datelike, Local, NaiveDate, TimeZone};
+use actix_web::{web, App, HttpResponse, HttpResponseBuilder, HttpServer, Responder};
+use serde::Deserialize;
+use std::io::Write;
+use std::sync::Arc;
+use univ_crawler::config::Config;
+use univ_crawler::crawler::{self, SchoolCrawl};
+use univ_crawler::diff;
+use univ_crawler::feed_cache::{CachedFeed, FeedCache, FeedFormat, Lookup};
+use univ_crawler::health::{self, HealthReport, HealthStatus};
+use univ_crawler::model::{self, Notice, PinnedMode};
+use univ_crawler::schools::{self, FetchOptions, HttpClient, School, ScrapeError};
+use univ_crawler::search::{self, SearchQuery, SearchResult};
+use univ_crawler::singleflight::SingleFlight;
+use univ_crawler::storage;
+use univ_crawler::store::Store;
+use univ_crawler::summary::{RunSummary, SchoolOutcome};
 
 const SITE_ROOT: &str = "public"; // GitHub Pages에 배포할 루트(빌드 산출물 저장 위치)
 
+/// 동시에 들어온 요청이 나눠 쓰는 수집 결과 (학교별 single-flight)
+type SharedCrawl = Arc<Result<Vec<Notice>, ScrapeError>>;
+
 #[actix_web::main]
 async fn main() -> std::io::Result<()> {
     // ── 원샷 모드: 파일만 만들고 종료 (액션/로컬용) ─────────────────────
     // 사용법: cargo run -- --oneshot  (또는 --one-shot)
+    //         수집 범위: --max-pages N --max-items N --details
+    //         상단 고정 글: --pinned include|exclude|only (환경 변수는 src/config.rs 참고)
+    // 수정 이력: cargo run -- history <학교> <글 번호>
+    // 검색: cargo run -- search <검색어> [--school 학교] [--from YYYY-MM-DD] [--to YYYY-MM-DD] [--limit N]
     let args: Vec<String> = std::env::args().collect();
     let is_oneshot = args.iter().any(|a| a == "--oneshot" || a == "--one-shot");
     // GitHub Actions에서는 자동으로 원샷 처리
     let is_ci = std::env::var("GITHUB_ACTIONS").is_ok();
+    let config = Config::from_env().apply_args(&args);
+    // 실행 간에 공지를 쌓아 두는 저장소. 피드/마크다운은 여기서 만듦
+    let store = Store::open(&config.db_path).map_err(|e| {
+        std::io::Error::other(format!("저장소를 열지 못했습니다 ({}): {e}", config.db_path.display()))
+    })?;
 
-    if is_oneshot || is_ci {
-        // ✅ 동기 크롤링을 별도 블로킹 스레드에서 실행 → 런타임 드롭 패닉 방지
-        let res = tokio::task::spawn_blocking(|| run_once_generate_files())
-            .await
-            .expect("spawn_blocking failed");
-
-        match res {
-            Ok(()) => {
-                println!("원샷 파일 생성 완료. 서버는 실행하지 않습니다.");
-                return Ok(());
+    if args.get(1).map(String::as_str) == Some("history") {
+        let (Some(school), Some(id)) = (args.get(2).and_then(|n| schools::find(n)), args.get(3)) else {
+            eprintln!("사용법: cargo run -- history <학교> <글 번호>  (예: history seoul 506633)");
+            std::process::exit(1);
+        };
+        match history_text(&store, school, id).map_err(std::io::Error::other)? {
+            Some(text) => print!("{text}"),
+            None => {
+                eprintln!("저장소에 {}:{id} 글이 없습니다.", school.key());
+                std::process::exit(1);
             }
+        }
+        return Ok(());
+    }
+
+    if args.get(1).map(String::as_str) == Some("search") {
+        let query = SearchQuery::from_args(&args[2..])
+            .and_then(|q| if q.text.is_empty() { Err("검색어가 없습니다.".to_string()) } else { Ok(q) });
+        let query = match query {
+            Ok(query) => query,
             Err(e) => {
-                eprintln!("원샷 파일 생성 실패: {e}");
-                return Err(e);
+                eprintln!("{e}\n사용법: cargo run -- search <검색어> [--school 학교] [--from YYYY-MM-DD] [--to YYYY-MM-DD] [--limit N]");
+                std::process::exit(1);
             }
+        };
+        let hits = store.search(&query).map_err(std::io::Error::other)?;
+        if hits.is_empty() {
+            println!("'{}'에 맞는 공지가 없습니다.", query.text);
+        }
+        for hit in &hits {
+            let n = &hit.notice;
+            let removed = if hit.removed_at.is_some() { " (삭제됨)" } else { "" };
+            println!("{} [{}] {}{removed}\n  {}", n.pub_date().format("%Y-%m-%d"), n.school, n.title, n.url);
         }
+        return Ok(());
     }
 
-    // ── 서버 실행 전 1회 파일 생성(블로킹 스레드) ──────────────────────
-    if let Err(e) = tokio::task::spawn_blocking(|| run_once_generate_files())
-        .await
-        .expect("spawn_blocking failed")
-    {
-        eprintln!("초기 파일 생성 중 오류: {e}");
+    // 모든 학교/요청이 같은 연결 풀을 쓰도록 클라이언트는 하나만 만듦
+    let client = HttpClient::new(&config.http)?;
+
+    if is_oneshot || is_ci {
+        // 종료 코드: 0 = 모든 학교 갱신, 2 = 일부 실패(성공한 학교만 배포), 1 = 모두 실패(배포 안 함)
+        let summary = run_once_generate_files(&client, &store, &config).await;
+        println!("\n{summary}");
+        write_step_summary(&summary);
+        match summary.exit_code() {
+            0 => println!("원샷 파일 생성 완료. 서버는 실행하지 않습니다."),
+            code => {
+                eprintln!("원샷 파일 생성: {}개 학교 실패", summary.failed());
+                std::process::exit(code);
+            }
+        }
+        return Ok(());
     }
 
-    // ── HTTP 서버: 요청 시 실시간 크롤링 → RSS XML 반환 ───────────────
-    HttpServer::new(|| {
+    // ── 서버 실행 전 1회 파일 생성 ──────────────────────────────────
+    let summary = run_once_generate_files(&client, &store, &config).await;
+    println!("\n{summary}");
+
+    // ── HTTP 서버: 학교별 피드를 캐시해 두고 TTL마다 뒤에서 새로 수집 ───────
+    // 방금 갱신한 학교는 기본 고정 글 모드의 RSS 피드를 미리 채워 둠
+    let cache = FeedCache::new(config.feed_ttl);
+    for (key, _, outcome) in &summary.schools {
+        if let (true, Some(school)) = (outcome.is_ok(), schools::find(key)) {
+            let xml = render_feed(&store, &config, school, config.pinned, FeedFormat::Rss, &[]);
+            cache.put(key, config.pinned, FeedFormat::Rss, xml);
+        }
+    }
+    let config = web::Data::new(config);
+    let client = web::Data::new(client);
+    let store = web::Data::new(store);
+    let cache = web::Data::new(cache);
+    let flights = web::Data::new(SingleFlight::<SharedCrawl>::new());
+    if cache.enabled() {
+        actix_web::rt::spawn(refresh_loop(
+            client.clone(),
+            store.clone(),
+            config.clone(),
+            cache.clone(),
+            flights.clone(),
+        ));
+    }
+    HttpServer::new(move || {
         App::new()
-            .route("/healthz", web::get().to(|| async { "ok" }))
-            // 예: /school-rss/sookmyung/rss.xml, /school-rss/seoul/rss.xml, /school-rss/dongduk/rss.xml
-            .route("/school-rss/{school}/rss.xml", web::get().to(rss_endpoint))
+            .app_data(config.clone())
+            .app_data(client.clone())
+            .app_data(store.clone())
+            .app_data(cache.clone())
+            .app_data(flights.clone())
+            .route("/healthz", web::get().to(healthz))
+            // 예: /school-rss/sookmyung/rss.xml, /school-rss/seoul/atom.xml, /school-rss/dongduk/feed.json
+            //     상단 고정 글: ?pinned=include|exclude|only
+            .route("/school-rss/{school}/{file}", web::get().to(feed_endpoint))
+            // 예: /api/history/seoul/506633 — 저장된 글의 수정 이력 (text/plain)
+            .route("/api/history/{school}/{id}", web::get().to(history_endpoint))
+            // 예: /api/search?q=장학금&school=dongduk&from=2025-01-01&to=2025-02-28 — 저장된 공지 검색 (JSON)
+            .route("/api/search", web::get().to(search_endpoint))
+            // 예: /search?q=장학금 — 같은 검색의 HTML 결과 페이지 (브라우저 검색창용)
+            .route("/search", web::get().to(search_page))
+            // 브라우저 검색창 등록용 OpenSearch 설명 문서
+            .route("/opensearch.xml", web::get().to(opensearch_endpoint))
     })
     .bind(("0.0.0.0", 8080))?
     .workers(2)
@@ -60,184 +147,611 @@ async fn main() -> std::io::Result<()> {
     .await
 }
 
-/* ───────────── 날짜 추출/변환 & 절대 URL 정규화 ───────────── */
+// GitHub Actions 작업 요약 페이지에 결과 표 추가
+fn write_step_summary(summary: &RunSummary) {
+    let Ok(path) = std::env::var("GITHUB_STEP_SUMMARY") else {
+        return;
+    };
+    let appended = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .and_then(|mut f| writeln!(f, "## 공지 피드 생성 결과\n\n{}", summary.markdown()));
+    if let Err(e) = appended {
+        eprintln!("작업 요약 쓰기 실패 ({path}): {e}");
+    }
+}
+
+/* ───────────── 날짜/URL 정규화 ───────────── */
+
+fn ensure_absolute_url(school: &dyn School, url: &str) -> String {
+    if url.starts_with("http://") || url.starts_with("https://") {
+        return url.to_string();
+    }
+    format!("{}{}", school.base_url(), url)
+}
+
+fn feed_dir(school: &dyn School) -> String {
+    format!("{}/school-rss/{}", SITE_ROOT, school.key())
+}
+
+// 설정 + 이미 본 글(저장소, 이전 rss.xml — 여기까지 왔으면 더 넘기지 않음)로 수집 범위 결정
+fn fetch_options(config: &Config, store: &Store, school: &dyn School) -> FetchOptions {
+    let mut seen = storage::load_feed_seen(&format!("{}/rss.xml", feed_dir(school)));
+    match store.known(school.key()) {
+        Ok(known) => seen.extend(known),
+        Err(e) => eprintln!("[STORE] {} 저장된 글 목록 읽기 실패: {e}", school.key()),
+    }
+    FetchOptions {
+        max_pages: config.max_pages,
+        max_items: config.max_items,
+        seen,
+        details: config.details,
+        concurrency: config.concurrency,
+    }
+}
 
-/// 문자열 전체에서 숫자/구분자만 남겨 토큰화 후 YYYY.MM.DD/… 패턴을 탐지
-fn extract_date_token(s: &str) -> Option<(i32, u32, u32)> {
-    // 1) 숫자/구분자('.','-','/')만 남기고 그 외는 공백으로 치환
-    let mut filtered = String::with_capacity(s.len());
-    for ch in s.chars() {
-        if ch.is_ascii_digit() || ch == '.' || ch == '-' || ch == '/' {
-            filtered.push(ch);
-        } else {
-            filtered.push(' ');
+// 게시일 파싱, 절대 URL, 학교 키/말머리 채우기
+fn normalize_notices(school: &dyn School, src: &[Notice]) -> Vec<Notice> {
+    src.iter()
+        .map(|n| Notice {
+            school: school.key().to_string(),
+            category: n.category.clone().or_else(|| model::category_from_title(&n.title)),
+            // 조회수/기타 텍스트 섞여도 날짜만 추출
+            published: n.published.or_else(|| model::parse_date(&n.date_raw)),
+            url: ensure_absolute_url(school, &n.url), // 절대 URL 보장
+            ..n.clone()
+        })
+        .collect()
+}
+
+/* ───────────── 파일 생성(정규화 적용) ───────────── */
+
+// 학교마다 따로 생성: 한 곳이 실패해도 나머지는 갱신하고, 실패한 곳은 이전 파일을 그대로 둠
+async fn run_once_generate_files(client: &HttpClient, store: &Store, config: &Config) -> RunSummary {
+    // 학교별 수집은 동시에, 저장/파일 쓰기/출력은 등록 순서대로
+    let results = crawler::crawl_all(client, config.concurrency, |s| fetch_options(config, store, s)).await;
+    let mut summary = RunSummary::default();
+    for (i, crawl) in results.into_iter().enumerate() {
+        if i > 0 {
+            println!();
         }
+        let school = crawl.school;
+        let outcome = generate_school_files(config, store, crawl);
+        if let SchoolOutcome::Failed { reason, .. } = &outcome {
+            eprintln!("{} 피드를 갱신하지 못해 기존 파일을 유지합니다: {reason}", school.display_name());
+        }
+        summary.push(school.key(), school.display_name(), outcome);
     }
 
-    // 2) 공백 기준으로 토큰화 → 각 토큰에서 구분자별로 날짜 패턴 시도
-    let seps = ['.', '-', '/'];
-    for token in filtered.split_whitespace() {
-        let t = token.trim().trim_end_matches('.');
-        for sep in seps {
-            let parts: Vec<_> = t.split(sep).map(|x| x.trim()).collect();
-            if parts.len() != 3 {
-                continue;
+    if client.cache().enabled() {
+        let c = client.cache().stats();
+        println!("[CACHE] 목록 페이지 조건부 요청: 304 재사용 {}건, 새로 받음 {}건", c.hits, c.misses);
+    }
+    for h in client.politeness().stats() {
+        println!(
+            "[POLITE] {}: 요청 {}건, 간격 대기 {:.1}초, robots.txt 금지 {}건",
+            h.host,
+            h.requests,
+            h.waited.as_secs_f32(),
+            h.disallowed
+        );
+    }
+    for b in client.breakers().snapshot() {
+        if b.state != "closed" {
+            eprintln!(
+                "[BREAKER] {}: {} (연속 실패 {}회, 마지막 오류: {})",
+                b.school,
+                b.state,
+                b.consecutive_failures,
+                b.last_error.as_deref().unwrap_or("-")
+            );
+        }
+    }
+    summary
+}
+
+fn generate_school_files(config: &Config, store: &Store, crawl: SchoolCrawl) -> SchoolOutcome {
+    let school = crawl.school;
+    // 일시적 장애(네트워크/서버)나 404·robots.txt 금지 등은 구조 점검 없이 그대로 보고
+    match &crawl.result {
+        Ok(_) | Err(ScrapeError::NoRows { .. } | ScrapeError::RowParse { .. }) => {}
+        Err(e) => {
+            return SchoolOutcome::Failed {
+                reason: e.to_string(),
+                transient: e.is_transient(),
             }
-            let (y, m, d) = (parts[0], parts[1], parts[2]);
-            // 연도 4자리 + 월/일 1~2자리 숫자
-            if y.len() == 4
-                && y.chars().all(|c| c.is_ascii_digit())
-                && m.chars().all(|c| c.is_ascii_digit())
-                && d.chars().all(|c| c.is_ascii_digit())
-            {
-                if let (Ok(yy), Ok(mm), Ok(dd)) =
-                    (y.parse::<i32>(), m.parse::<u32>(), d.parse::<u32>())
-                {
-                    if NaiveDate::from_ymd_opt(yy, mm, dd).is_some() {
-                        return Some((yy, mm, dd));
-                    }
-                }
+        }
+    }
+    // 목록이 제대로 읽혔는지 점검. 사이트 개편이 의심되면 깨진 피드를 배포하지 않음
+    let baseline = health::load_baseline(&config.state_dir, school.key());
+    let report = HealthReport::evaluate(school.key(), &crawl.stats, crawl.result.as_deref(), baseline);
+    if report.is_broken() {
+        eprintln!("{report}");
+        return SchoolOutcome::Failed {
+            reason: format!(
+                "목록 페이지 구조 변경 의심 — {}",
+                report.problems.first().map_or("", String::as_str)
+            ),
+            transient: false,
+        };
+    }
+    println!("{report}");
+    if let Err(e) = health::save_baseline(&config.state_dir, &report) {
+        eprintln!("[HEALTH] 기준 기록 저장 실패: {e}");
+    }
+    let raw = match crawl.result {
+        Ok(raw) => raw,
+        Err(e) => {
+            return SchoolOutcome::Failed {
+                reason: e.to_string(),
+                transient: e.is_transient(),
             }
         }
+    };
+
+    // 저장소가 비어 있어도(CI 캐시 만료 등) 이전에 배포한 피드의 글을 이어 실음
+    let previous = storage::load_feed_notices(&format!("{}/rss.xml", feed_dir(school)), school.key());
+    match store.import(&previous, model::now_kst()) {
+        Ok(0) => {}
+        Ok(n) => println!("[STORE] {}: 이전 rss.xml에서 {n}건을 가져왔습니다.", school.key()),
+        Err(e) => eprintln!("[STORE] {} 이전 피드 가져오기 실패: {e}", school.key()),
+    }
+    let notices = match store_and_load(store, school, &raw, config, config.pinned) {
+        Ok(notices) => notices,
+        Err(e) => {
+            return SchoolOutcome::Failed {
+                reason: format!("저장소 오류: {e}"),
+                transient: false,
+            }
+        }
+    };
+    println!("<<{} 공지사항>>", school.display_name());
+    for n in &notices {
+        println!("{} [{}] ({})", n.title, n.pub_date_rfc2822(), n.url);
+    }
+    // 네 파일을 모두 만든 뒤 한꺼번에 교체 (일부만 바뀐 채로 남지 않게)
+    let dir = feed_dir(school);
+    let files = [
+        (format!("{dir}/rss.xml"), school.create_rss(&notices).to_string()),
+        (format!("{dir}/atom.xml"), school.create_atom(&notices, &config.public_url).to_string()),
+        (format!("{dir}/feed.json"), school.create_json_feed(&notices, &config.public_url).to_json()),
+        (
+            format!("{dir}/index.md"),
+            storage::render_markdown(&notices, &format!("{} 학사 공지", school.display_name())),
+        ),
+    ];
+    let written = storage::write_files_atomically(&files);
+    match written {
+        Ok(()) => SchoolOutcome::Updated {
+            items: notices.len(),
+            health: report.status,
+        },
+        Err(e) => SchoolOutcome::Failed {
+            reason: format!("파일 쓰기 실패: {e}"),
+            transient: false,
+        },
     }
-    None
 }
 
-/// pubDate용 RFC 2822 변환. (실패 시 현재 시각)
-fn to_rfc2822(date_raw: &str) -> String {
-    if let Some((yy, mm, dd)) = extract_date_token(date_raw) {
-        if let Some(naive) = NaiveDate::from_ymd_opt(yy, mm, dd) {
-            if let Some(dt) =
-                Local.with_ymd_and_hms(naive.year(), naive.month(), naive.day(), 0, 0, 0).single()
-            {
-                return dt.to_rfc2822();
+// 이번 수집 결과를 저장소에 반영하고, 피드에 실을 최근 공지를 저장소에서 읽음
+fn store_and_load(
+    store: &Store,
+    school: &dyn School,
+    raw: &[Notice],
+    config: &Config,
+    pinned: PinnedMode,
+) -> rusqlite::Result<Vec<Notice>> {
+    let diff = store.upsert(school.key(), &normalize_notices(school, raw), model::now_kst())?;
+    println!("{diff}");
+    load_feed(store, school, config, pinned)
+}
+
+// 피드에 실을 최근 공지를 저장소에서 읽음
+fn load_feed(store: &Store, school: &dyn School, config: &Config, pinned: PinnedMode) -> rusqlite::Result<Vec<Notice>> {
+    let now = model::now_kst();
+    // 피드 보존 범위: 최근 feed_items건, feed_max_age_days일 이내
+    let since = (config.feed_max_age_days > 0).then(|| now - chrono::Duration::days(config.feed_max_age_days.into()));
+    let mut notices = Vec::new();
+    for stored in store.recent_since(school.key(), pinned, config.feed_items, since)? {
+        let mut n = stored.notice;
+        // 수정된 글은 설명 앞에 무엇이 바뀌었는지 표시
+        if config.change_notes && Some(stored.last_changed) > n.first_seen {
+            if let Some((at, changes)) = store.last_changes(&n.guid())? {
+                let note = diff::change_note(at, &changes);
+                n.summary = Some(match n.summary {
+                    Some(summary) => format!("{note}\n\n{summary}"),
+                    None => note,
+                });
             }
         }
+        notices.push(n);
     }
-    Local::now().to_rfc2822()
+    Ok(notices)
 }
 
-fn ensure_absolute_url(school: &str, url: &str) -> String {
-    if url.starts_with("http://") || url.starts_with("https://") {
-        return url.to_string();
+// 저장된 글 1건의 수정 이력 (없는 글이면 None)
+fn history_text(store: &Store, school: &dyn School, id: &str) -> rusqlite::Result<Option<String>> {
+    let guid = format!("{}:{id}", school.key());
+    Ok(store
+        .history(&guid)?
+        .map(|(stored, revisions)| diff::render_history(&stored.notice, &revisions)))
+}
+
+/* ───────────── HTTP 핸들러 ───────────── */
+
+// 서버는 살아 있으면 200. 차단기가 열린 학교가 있으면 status = "degraded"
+async fn healthz(
+    client: web::Data<HttpClient>,
+    cache: web::Data<FeedCache>,
+    flights: web::Data<SingleFlight<SharedCrawl>>,
+) -> impl Responder {
+    let breakers = client.breakers().snapshot();
+    let status = if breakers.iter().any(|b| b.state != "closed") {
+        "degraded"
+    } else {
+        "ok"
+    };
+    HttpResponse::Ok().json(serde_json::json!({
+        "status": status,
+        "breakers": breakers,
+        "http_cache": client.cache().stats(),
+        "feed_cache": cache.snapshot(),
+        // 다른 요청이 진행 중인 수집 결과를 기다려 받은 요청 수 (학교별)
+        "coalesced_requests": flights.coalesced(),
+    }))
+}
+
+async fn history_endpoint(path: web::Path<(String, String)>, store: web::Data<Store>) -> impl Responder {
+    let (name, id) = path.into_inner();
+    let Some(school) = schools::find(&name) else {
+        return HttpResponse::NotFound().body(format!("unknown school: {name}"));
+    };
+    let not_found = format!("unknown notice: {}:{id}", school.key());
+    match with_store(&store, move |store| history_text(store, school, &id)).await {
+        Ok(Some(text)) => HttpResponse::Ok().content_type("text/plain; charset=utf-8").body(text),
+        Ok(None) => HttpResponse::NotFound().body(not_found),
+        Err(e) => {
+            eprintln!("[STORE] 수정 이력 읽기 실패: {e}");
+            HttpResponse::InternalServerError().body("store error")
+        }
     }
-    match school {
-        "sookmyung" | "sm" | "숙명" => format!("https://www.sookmyung.ac.kr{}", url),
-        "seoul" | "swu" | "서울" => format!("https://www.swu.ac.kr{}", url),
-        "dongduk" | "dd" | "동덕" => format!("https://www.dongduk.ac.kr{}", url),
-        _ => url.to_string(),
+}
+
+#[derive(Deserialize)]
+struct SearchParams {
+    q: Option<String>,
+    school: Option<String>,
+    from: Option<String>,
+    to: Option<String>,
+    limit: Option<usize>,
+}
+
+// 한 번에 돌려주는 검색 결과는 최대 100건. 잘못된 요청이면 돌려줄 오류 응답
+async fn run_search(
+    params: SearchParams,
+    store: &web::Data<Store>,
+) -> Result<(String, Vec<SearchResult>), HttpResponse> {
+    let text = params.q.unwrap_or_default();
+    let query = SearchQuery::new(&text)
+        .school(params.school.as_deref())
+        .and_then(|q| q.dates(params.from.as_deref(), params.to.as_deref()));
+    let mut query = match query {
+        Ok(query) if !query.text.is_empty() => query,
+        Ok(_) => return Err(HttpResponse::BadRequest().body("missing q")),
+        Err(e) => return Err(HttpResponse::BadRequest().body(e)),
+    };
+    query.limit = params.limit.unwrap_or(query.limit).clamp(1, 100);
+    let text = query.text.clone();
+    match with_store(store, move |store| store.search(&query)).await {
+        Ok(hits) => Ok((text, hits.iter().map(SearchResult::from).collect())),
+        Err(e) => {
+            eprintln!("[STORE] 검색 실패: {e}");
+            Err(HttpResponse::InternalServerError().body("store error"))
+        }
     }
 }
 
-// sookmyung::Notice 형식으로 정규화
-fn normalize_notices(school_key: &str, src: &[sookmyung::Notice]) -> Vec<sookmyung::Notice> {
-    src.iter()
-        .map(|n| sookmyung::Notice {
-            title: n.title.clone(),
-            date: to_rfc2822(&n.date),                    // 조회수/기타 텍스트 섞여도 날짜만 추출
-            url: ensure_absolute_url(school_key, &n.url), // 절대 URL 보장
-        })
-        .collect()
+async fn search_endpoint(params: web::Query<SearchParams>, store: web::Data<Store>) -> impl Responder {
+    match run_search(params.into_inner(), &store).await {
+        Ok((query, results)) => HttpResponse::Ok().json(serde_json::json!({
+            "query": query,
+            "count": results.len(),
+            "results": results,
+        })),
+        Err(res) => res,
+    }
 }
 
-/* ───────────── 파일 생성(정규화 적용) ───────────── */
+// 브라우저 검색창에서 온 검색 (HTML 결과 페이지)
+async fn search_page(params: web::Query<SearchParams>, store: web::Data<Store>) -> impl Responder {
+    match run_search(params.into_inner(), &store).await {
+        Ok((query, results)) => HttpResponse::Ok()
+            .content_type("text/html; charset=utf-8")
+            .body(search::results_html(&query, &results)),
+        Err(res) => res,
+    }
+}
 
-fn run_once_generate_files() -> Result<(), IoError> {
-    // 숙명
-    let sm_raw = sookmyung::fetch_notices()
-        .map_err(|e| IoError::new(ErrorKind::Other, e.to_string()))?;
-    let sm = normalize_notices("sookmyung", &sm_raw);
-    println!("<<숙명여자대학교 공지사항>>");
-    for n in &sm {
-        println!("{} [{}] ({})", n.title, n.date, n.url);
-    }
-    let sm_rss = sookmyung::create_rss(&sm);
-    storage::save_rss_xml(&sm_rss, &format!("{}/school-rss/sookmyung/rss.xml", SITE_ROOT))?;
-    storage::save_markdown(
-        &sm,
-        &format!("{}/school-rss/sookmyung/index.md", SITE_ROOT),
-        "숙명여자대학교 학사 공지",
-    )?;
-
-    // 동덕
-    let dd_raw = dongduk::fetch_notices()
-        .map_err(|e| IoError::new(ErrorKind::Other, e.to_string()))?;
-    let dd = normalize_notices("dongduk", &dd_raw);
-    println!("\n<<동덕여자대학교 공지사항>>");
-    for n in &dd {
-        println!("{} [{}] ({})", n.title, n.date, n.url);
-    }
-    let dd_rss = dongduk::create_rss(&dd);
-    storage::save_rss_xml(&dd_rss, &format!("{}/school-rss/dongduk/rss.xml", SITE_ROOT))?;
-    storage::save_markdown(
-        &dd,
-        &format!("{}/school-rss/dongduk/index.md", SITE_ROOT),
-        "동덕여자대학교 학사 공지",
-    )?;
-
-    // 서울여대
-    let sw_raw = seoul::fetch_notices()
-        .map_err(|e| IoError::new(ErrorKind::Other, e.to_string()))?;
-    let sw = normalize_notices("seoul", &sw_raw);
-    println!("\n<<서울여자대학교 공지사항>>");
-    for n in &sw {
-        println!("{} [{}] ({})", n.title, n.date, n.url);
-    }
-    let sw_rss = seoul::create_rss(&sw);
-    storage::save_rss_xml(&sw_rss, &format!("{}/school-rss/seoul/rss.xml", SITE_ROOT))?;
-    storage::save_markdown(
-        &sw,
-        &format!("{}/school-rss/seoul/index.md", SITE_ROOT),
-        "서울여자대학교 학사 공지",
-    )?;
-
-    Ok(())
+// 검색 URL 템플릿은 설정한 공개 주소 기준 (요청의 Host 헤더는 믿지 않음)
+async fn opensearch_endpoint(config: web::Data<Config>) -> impl Responder {
+    let base = schools::escape_html(&config.public_url);
+    let xml = format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<OpenSearchDescription xmlns="http://a9.com/-/spec/opensearch/1.1/">
+  <ShortName>학사 공지 검색</ShortName>
+  <Description>저장된 학사 공지의 제목·본문·첨부 이름 검색</Description>
+  <InputEncoding>UTF-8</InputEncoding>
+  <Url type="text/html" method="get" template="{base}/search?q={{searchTerms}}"/>
+  <Url type="application/json" template="{base}/api/search?q={{searchTerms}}"/>
+  <Url type="application/opensearchdescription+xml" rel="self" template="{base}/opensearch.xml"/>
+</OpenSearchDescription>
+"#
+    );
+    HttpResponse::Ok()
+        .content_type("application/opensearchdescription+xml; charset=utf-8")
+        .body(xml)
 }
 
-/* ───────────── HTTP 핸들러 ───────────── */
+#[derive(Deserialize)]
+struct FeedQuery {
+    /// include | exclude | only (없으면 설정값)
+    pinned: Option<String>,
+}
 
-async fn rss_endpoint(path: web::Path<(String,)>) -> impl Responder {
-    let school = path.into_inner().0.to_lowercase();
-    let result = web::block(move || generate_rss_xml(&school)).await;
+async fn feed_endpoint(
+    path: web::Path<(String, String)>,
+    query: web::Query<FeedQuery>,
+    config: web::Data<Config>,
+    client: web::Data<HttpClient>,
+    store: web::Data<Store>,
+    cache: web::Data<FeedCache>,
+    flights: web::Data<SingleFlight<SharedCrawl>>,
+) -> impl Responder {
+    let (name, file) = path.into_inner();
+    let Some(school) = schools::find(&name) else {
+        return HttpResponse::NotFound().body(format!("unknown school: {name}"));
+    };
+    let Some(format) = FeedFormat::from_file_name(&file) else {
+        return HttpResponse::NotFound().body(format!("unknown feed: {file}"));
+    };
+    let pinned = match query.pinned.as_deref().map(str::parse::<PinnedMode>) {
+        None => config.pinned,
+        Some(Ok(mode)) => mode,
+        Some(Err(e)) => return HttpResponse::BadRequest().body(e),
+    };
+    let key = school.key();
+    match cache.get(key, pinned, format) {
+        Lookup::Fresh(feed) => return cached_response(&feed, format, "HIT"),
+        Lookup::Stale(feed) => {
+            // 지난 피드를 바로 주고, 새로 수집은 뒤에서 (이미 누가 하고 있으면 맡김)
+            if cache.begin_refresh(key) {
+                let cache = cache.clone();
+                actix_web::rt::spawn(async move {
+                    refresh_feeds(&client, &store, &config, &cache, &flights, school).await;
+                    cache.end_refresh(key);
+                });
+            }
+            return cached_response(&feed, format, "STALE");
+        }
+        // 처음 보는 피드: 요청 안에서 학교 사이트를 긁지 않고 저장소에 있는 글로 바로 만들고,
+        // 새로 수집은 뒤에서 (끝나면 이 피드도 함께 다시 만듦)
+        Lookup::Missing if cache.enabled() => {
+            let xml = render_feed_in_pool(&store, &config, school, pinned, format, None).await;
+            cache.put(key, pinned, format, xml.clone());
+            if cache.begin_refresh(key) {
+                let cache = cache.clone();
+                actix_web::rt::spawn(async move {
+                    refresh_feeds(&client, &store, &config, &cache, &flights, school).await;
+                    cache.end_refresh(key);
+                });
+            }
+            return feed_response(format, "MISS").body(xml);
+        }
+        Lookup::Missing => {}
+    }
 
-    match result {
-        Ok(Ok(xml)) => HttpResponse::Ok()
-            .content_type("application/rss+xml; charset=utf-8")
-            .body(xml),
-        Ok(Err(e)) => {
-            eprintln!("generate_rss_xml error: {e}");
-            HttpResponse::InternalServerError().body("failed to generate rss")
+    // 캐시를 끈 경우(TTL 0): 요청마다 수집. 같은 학교를 수집 중인 요청이 있으면 그 결과를 기다림
+    let crawl = crawl_shared(&client, &store, &config, &flights, school).await;
+    match crawl.as_ref() {
+        Ok(_) => {
+            let xml = render_feed_in_pool(&store, &config, school, pinned, format, Some(crawl.clone())).await;
+            cache.put(key, pinned, format, xml.clone());
+            feed_response(format, "MISS").body(xml)
         }
         Err(e) => {
-            eprintln!("web::block join error: {e}");
-            HttpResponse::InternalServerError().finish()
+            eprintln!("crawl_into_store error: {e}");
+            match e {
+                ScrapeError::Transport { source, .. } if source.is_timeout() => {
+                    HttpResponse::GatewayTimeout().body("upstream timed out")
+                }
+                ScrapeError::Transport { .. }
+                | ScrapeError::HttpStatus { .. }
+                | ScrapeError::Decode { .. } => {
+                    HttpResponse::BadGateway().body("upstream site unavailable")
+                }
+                ScrapeError::NoRows { .. } | ScrapeError::RowParse { .. } => {
+                    HttpResponse::InternalServerError().body("upstream page structure changed")
+                }
+                ScrapeError::RobotsDisallowed { .. } => {
+                    HttpResponse::Forbidden().body("robots.txt disallows this board")
+                }
+                ScrapeError::CircuitOpen { retry_in, .. } => HttpResponse::ServiceUnavailable()
+                    .insert_header(("Retry-After", retry_in.as_secs().max(1).to_string()))
+                    .body("upstream site failing repeatedly, requests paused"),
+                // robots.txt를 못 받은 것은 일시적 장애. 차단기 대기 시간만큼 뒤에 다시 오도록
+                ScrapeError::RobotsUnreachable { .. } => HttpResponse::ServiceUnavailable()
+                    .insert_header((
+                        "Retry-After",
+                        config.http.breaker_cooldown.as_secs().max(1).to_string(),
+                    ))
+                    .body("robots.txt unreachable, requests paused"),
+            }
         }
     }
 }
 
-fn generate_rss_xml(school: &str) -> Result<String, IoError> {
-    match school {
-        "sookmyung" | "sm" | "숙명" => {
-            let items_raw =
-                sookmyung::fetch_notices().map_err(|e| IoError::new(ErrorKind::Other, e.to_string()))?;
-            let items = normalize_notices("sookmyung", &items_raw);
-            Ok(sookmyung::create_rss(&items).to_string())
+// 피드 응답 공통 헤더. JSON Feed는 다른 사이트의 브라우저 위젯에서도 읽을 수 있게 CORS 허용
+fn feed_response(format: FeedFormat, state: &'static str) -> HttpResponseBuilder {
+    let mut res = HttpResponse::Ok();
+    res.content_type(format.content_type()).insert_header(("X-Feed-Cache", state));
+    if format == FeedFormat::Json {
+        res.insert_header(("Access-Control-Allow-Origin", "*"));
+    }
+    res
+}
+
+// 캐시된 피드 응답. 마지막 수집이 실패했으면 Warning 헤더로 알림
+fn cached_response(feed: &CachedFeed, format: FeedFormat, state: &'static str) -> HttpResponse {
+    let mut res = feed_response(format, state);
+    res.insert_header(("Age", feed.built_at.elapsed().as_secs().to_string()));
+    if feed.last_error.is_some() {
+        res.insert_header(("Warning", "111 - \"Revalidation Failed\""));
+    }
+    res.body(feed.xml.clone())
+}
+
+// 캐시된 피드를 미리 새로 수집. TTL의 절반마다 깨어나 그보다 오래된 학교를 갱신하므로
+// 요청은 보통 TTL 안의 피드를 받음
+async fn refresh_loop(
+    client: web::Data<HttpClient>,
+    store: web::Data<Store>,
+    config: web::Data<Config>,
+    cache: web::Data<FeedCache>,
+    flights: web::Data<SingleFlight<SharedCrawl>>,
+) {
+    let period = cache.ttl() / 2;
+    let mut tick = tokio::time::interval(period);
+    tick.tick().await; // 첫 틱은 바로 옴 (서버 시작 직전에 이미 수집함)
+    loop {
+        tick.tick().await;
+        for key in cache.due(period) {
+            let Some(school) = schools::find(key) else {
+                continue;
+            };
+            if cache.begin_refresh(key) {
+                refresh_feeds(&client, &store, &config, &cache, &flights, school).await;
+                cache.end_refresh(key);
+            }
         }
-        "seoul" | "swu" | "서울" => {
-            let items_raw =
-                seoul::fetch_notices().map_err(|e| IoError::new(ErrorKind::Other, e.to_string()))?;
-            let items = normalize_notices("seoul", &items_raw);
-            Ok(seoul::create_rss(&items).to_string())
+    }
+}
+
+// 학교 1곳을 새로 수집해 캐시된 모든 고정 글 모드/형식의 피드를 다시 만듦.
+// 실패하면 지난 피드를 그대로 두고 오류만 기록
+async fn refresh_feeds(
+    client: &HttpClient,
+    store: &web::Data<Store>,
+    config: &web::Data<Config>,
+    cache: &FeedCache,
+    flights: &SingleFlight<SharedCrawl>,
+    school: &'static dyn School,
+) {
+    let key = school.key();
+    let crawl = crawl_shared(client, store, config, flights, school).await;
+    match crawl.as_ref() {
+        Ok(_) => {
+            for (pinned, format) in cache.variants(key) {
+                let xml = render_feed_in_pool(store, config, school, pinned, format, Some(crawl.clone())).await;
+                cache.put(key, pinned, format, xml);
+            }
         }
-        "dongduk" | "dd" | "동덕" => {
-            let items_raw =
-                dongduk::fetch_notices().map_err(|e| IoError::new(ErrorKind::Other, e.to_string()))?;
-            let items = normalize_notices("dongduk", &items_raw);
-            Ok(dongduk::create_rss(&items).to_string())
+        Err(e) => {
+            eprintln!("[FEED] {key} 새로 수집 실패, 지난 피드를 계속 씁니다: {e}");
+            cache.fail(key, &e.to_string());
         }
-        other => Err(IoError::new(
-            ErrorKind::InvalidInput,
-            format!("unknown school: {other}"),
-        )),
+    }
+}
+
+// 학교별로 한 번에 하나만 수집 (진행 중이면 합류)
+async fn crawl_shared(
+    client: &HttpClient,
+    store: &web::Data<Store>,
+    config: &web::Data<Config>,
+    flights: &SingleFlight<SharedCrawl>,
+    school: &'static dyn School,
+) -> SharedCrawl {
+    flights
+        .run(school.key(), || async { Arc::new(crawl_into_store(client, store, config, school).await) })
+        .await
+}
+
+// 학교 사이트를 새로 읽어 저장소에 반영. 돌려주는 목록은 저장소를 읽지 못할 때 대신 씀
+async fn crawl_into_store(
+    client: &HttpClient,
+    store: &web::Data<Store>,
+    config: &web::Data<Config>,
+    school: &'static dyn School,
+) -> Result<Vec<Notice>, ScrapeError> {
+    let opts = {
+        let config = config.clone();
+        with_store(store, move |store| fetch_options(&config, store, school)).await
+    };
+    let (result, stats) = school.fetch_with_stats(client, &opts).await;
+    if !result.as_ref().is_err_and(|e| e.is_transient()) {
+        let report = HealthReport::evaluate(school.key(), &stats, result.as_deref(), None);
+        if report.status != HealthStatus::Ok {
+            eprintln!("{report}");
+        }
+    }
+    let crawled = normalize_notices(school, &result?);
+    let upserted = {
+        let crawled = crawled.clone();
+        with_store(store, move |store| store.upsert(school.key(), &crawled, model::now_kst())).await
+    };
+    match upserted {
+        Ok(diff) => println!("{diff}"),
+        Err(e) => eprintln!("[STORE] {} 저장소 오류: {e}", school.key()),
+    }
+    Ok(crawled)
+}
+
+// 저장소(SQLite)는 동기 API라, 서버에서는 actix 워커를 막지 않도록 블로킹 스레드 풀에서 다룸
+async fn with_store<T, F>(store: &web::Data<Store>, f: F) -> T
+where
+    F: FnOnce(&Store) -> T + Send + 'static,
+    T: Send + 'static,
+{
+    let store = store.clone();
+    web::block(move || f(&store)).await.expect("저장소 작업 스레드가 중단됨")
+}
+
+// 서버용 render_feed: 블로킹 스레드 풀에서 만듦. `crawl`은 저장소 오류 때 대신 쓸 수집 결과
+async fn render_feed_in_pool(
+    store: &web::Data<Store>,
+    config: &web::Data<Config>,
+    school: &'static dyn School,
+    pinned: PinnedMode,
+    format: FeedFormat,
+    crawl: Option<SharedCrawl>,
+) -> String {
+    let config = config.clone();
+    with_store(store, move |store| {
+        let crawled = match crawl.as_deref() {
+            Some(Ok(crawled)) => crawled.as_slice(),
+            _ => &[],
+        };
+        render_feed(store, &config, school, pinned, format, crawled)
+    })
+    .await
+}
+
+// 저장소에서 피드 XML 만들기. 저장소 오류면 이번에 수집한 목록만으로
+fn render_feed(
+    store: &Store,
+    config: &Config,
+    school: &dyn School,
+    pinned: PinnedMode,
+    format: FeedFormat,
+    crawled: &[Notice],
+) -> String {
+    let items = load_feed(store, school, config, pinned).unwrap_or_else(|e| {
+        eprintln!("[STORE] {} 저장소 오류: {e}", school.key());
+        pinned.apply(crawled.to_vec())
+    });
+    match format {
+        FeedFormat::Rss => school.create_rss(&items).to_string(),
+        FeedFormat::Atom => school.create_atom(&items, &config.public_url).to_string(),
+        FeedFormat::Json => school.create_json_feed(&items, &config.public_url).to_json(),
     }
 }
@@ -0,0 +1,371 @@
+// src/model.rs
+use chrono::{DateTime, FixedOffset, NaiveDate, TimeZone, Timelike, Utc};
+use select::document::Document;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// 학교 게시판들이 쓰는 기준 시간대 (KST, UTC+9)
+pub fn kst() -> FixedOffset {
+    FixedOffset::east_opt(9 * 3600).expect("valid offset")
+}
+
+/// 현재 시각 (KST)
+pub fn now_kst() -> DateTime<FixedOffset> {
+    Utc::now().with_timezone(&kst())
+}
+
+/// 학교 공통 공지 모델
+#[derive(Debug, Clone, PartialEq)]
+pub struct Notice {
+    /// 게시판 고유 글 번호 (articleNo, fn_goView id, pkid 등). 모르면 None
+    pub id: Option<String>,
+    /// 학교 키 (예: "sookmyung")
+    pub school: String,
+    pub title: String,
+    /// 분류 (예: "[학점교류]" 말머리)
+    pub category: Option<String>,
+    /// 작성자/담당 부서
+    pub author: Option<String>,
+    pub views: Option<u32>,
+    /// 상단 고정("공지") 글 여부
+    pub pinned: bool,
+    /// 게시판에 보이는 날짜 원문 (조회수 등이 섞여 있을 수 있음)
+    pub date_raw: String,
+    /// `date_raw`에서 추출한 게시일 (KST 자정)
+    pub published: Option<DateTime<FixedOffset>>,
+    pub url: String,
+    /// 상세 페이지 본문 HTML (상세 수집을 켠 경우)
+    pub body_html: Option<String>,
+    /// 본문 앞부분 평문 요약
+    pub summary: Option<String>,
+    /// 첨부파일 (HWP/PDF/XLSX 등)
+    pub attachments: Vec<Attachment>,
+    /// 수집 시각
+    pub fetched_at: DateTime<FixedOffset>,
+    /// 저장소에 처음 기록된 시각 (저장소에서 읽은 경우만)
+    pub first_seen: Option<DateTime<FixedOffset>>,
+    /// 저장소에서 내용이 마지막으로 바뀐 시각 (저장소에서 읽은 경우만)
+    pub last_changed: Option<DateTime<FixedOffset>>,
+}
+
+/// 상세 페이지에서 읽은 정보
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NoticeDetail {
+    pub body_html: Option<String>,
+    pub summary: Option<String>,
+    /// 작성자/담당 부서
+    pub author: Option<String>,
+    /// 게시 시각 (목록보다 정밀하면 published를 덮어씀)
+    pub posted_at: Option<DateTime<FixedOffset>>,
+    pub attachments: Vec<Attachment>,
+}
+
+/// 첨부파일 1개
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Attachment {
+    pub name: String,
+    pub url: String,
+    /// 바이트 단위 크기 (페이지에 표시된 경우)
+    pub size: Option<u64>,
+    /// 확장자로 추정한 MIME 타입
+    pub mime: Option<String>,
+}
+
+impl Attachment {
+    pub fn new(name: String, url: String, size: Option<u64>) -> Self {
+        let mime = mime_from_name(&name).or_else(|| mime_from_name(&url)).map(str::to_string);
+        Attachment { name, url, size, mime }
+    }
+
+    /// "1.2 MB" 같은 사람이 읽는 크기
+    pub fn size_label(&self) -> Option<String> {
+        let size = self.size? as f64;
+        Some(if size >= 1024.0 * 1024.0 {
+            format!("{:.1} MB", size / 1024.0 / 1024.0)
+        } else if size >= 1024.0 {
+            format!("{:.0} KB", size / 1024.0)
+        } else {
+            format!("{size} B")
+        })
+    }
+}
+
+impl Notice {
+    pub fn new(school: &str, title: String, date_raw: String, url: String) -> Self {
+        Notice {
+            id: None,
+            school: school.to_string(),
+            title,
+            category: None,
+            author: None,
+            views: None,
+            pinned: false,
+            published: parse_date(&date_raw),
+            date_raw,
+            url,
+            body_html: None,
+            summary: None,
+            attachments: Vec::new(),
+            fetched_at: now_kst(),
+            first_seen: None,
+            last_changed: None,
+        }
+    }
+
+    /// 상세 페이지 결과 반영. 상세 쪽에 값이 있는 항목만 덮어씀
+    pub fn apply_detail(&mut self, detail: NoticeDetail) {
+        if detail.body_html.is_some() {
+            self.body_html = detail.body_html;
+        }
+        if detail.summary.is_some() {
+            self.summary = detail.summary;
+        }
+        if detail.author.is_some() {
+            self.author = detail.author;
+        }
+        if detail.posted_at.is_some() {
+            self.published = detail.posted_at;
+        }
+        if !detail.attachments.is_empty() {
+            self.attachments = detail.attachments;
+        }
+    }
+
+    /// 영구 GUID: "학교키:게시판 글 번호". 글 번호를 모르면 URL
+    pub fn guid(&self) -> String {
+        match &self.id {
+            Some(id) => format!("{}:{}", self.school, id),
+            None => self.url.clone(),
+        }
+    }
+
+    /// 피드에 쓸 게시일. 날짜를 못 읽었으면 처음 본 시각, 그것도 모르면 수집 시각
+    pub fn pub_date(&self) -> DateTime<FixedOffset> {
+        self.published.or(self.first_seen).unwrap_or(self.fetched_at)
+    }
+
+    /// 마지막으로 수정된 시각 (Atom `updated`). 처음 본 뒤로 바뀐 적 없으면 게시일
+    pub fn updated(&self) -> DateTime<FixedOffset> {
+        let published = self.pub_date();
+        match self.last_changed {
+            Some(changed) if Some(changed) > self.first_seen => changed.max(published),
+            _ => published,
+        }
+    }
+
+    /// RFC 2822 형식 게시일 (RSS pubDate / 마크다운용)
+    pub fn pub_date_rfc2822(&self) -> String {
+        self.pub_date().to_rfc2822()
+    }
+}
+
+/// 피드에 상단 고정 글을 어떻게 담을지
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum PinnedMode {
+    /// 모두 포함 (기본)
+    #[default]
+    Include,
+    /// 고정 글 제외
+    Exclude,
+    /// 고정 글만
+    Only,
+}
+
+impl PinnedMode {
+    pub fn apply(self, notices: Vec<Notice>) -> Vec<Notice> {
+        match self {
+            PinnedMode::Include => notices,
+            PinnedMode::Exclude => notices.into_iter().filter(|n| !n.pinned).collect(),
+            PinnedMode::Only => notices.into_iter().filter(|n| n.pinned).collect(),
+        }
+    }
+}
+
+impl FromStr for PinnedMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "include" | "all" => Ok(PinnedMode::Include),
+            "exclude" | "none" => Ok(PinnedMode::Exclude),
+            "only" => Ok(PinnedMode::Only),
+            other => Err(format!("pinned 값은 include/exclude/only 중 하나여야 합니다: {other}")),
+        }
+    }
+}
+
+impl fmt::Display for PinnedMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            PinnedMode::Include => "include",
+            PinnedMode::Exclude => "exclude",
+            PinnedMode::Only => "only",
+        })
+    }
+}
+
+/* ───────────── 날짜 추출/변환 ───────────── */
+
+/// 문자열 전체에서 숫자/구분자만 남겨 토큰화 후 YYYY.MM.DD/… 패턴을 탐지
+pub fn extract_date_token(s: &str) -> Option<(i32, u32, u32)> {
+    // 1) 숫자/구분자('.','-','/')만 남기고 그 외는 공백으로 치환
+    let mut filtered = String::with_capacity(s.len());
+    for ch in s.chars() {
+        if ch.is_ascii_digit() || ch == '.' || ch == '-' || ch == '/' {
+            filtered.push(ch);
+        } else {
+            filtered.push(' ');
+        }
+    }
+
+    // 2) 공백 기준으로 토큰화 → 각 토큰에서 구분자별로 날짜 패턴 시도
+    let seps = ['.', '-', '/'];
+    for token in filtered.split_whitespace() {
+        let t = token.trim().trim_end_matches('.');
+        for sep in seps {
+            let parts: Vec<_> = t.split(sep).map(|x| x.trim()).collect();
+            if parts.len() != 3 {
+                continue;
+            }
+            let (y, m, d) = (parts[0], parts[1], parts[2]);
+            // 연도 4자리 + 월/일 1~2자리 숫자
+            if y.len() == 4
+                && y.chars().all(|c| c.is_ascii_digit())
+                && m.chars().all(|c| c.is_ascii_digit())
+                && d.chars().all(|c| c.is_ascii_digit())
+            {
+                if let (Ok(yy), Ok(mm), Ok(dd)) =
+                    (y.parse::<i32>(), m.parse::<u32>(), d.parse::<u32>())
+                {
+                    if NaiveDate::from_ymd_opt(yy, mm, dd).is_some() {
+                        return Some((yy, mm, dd));
+                    }
+                }
+            }
+        }
+    }
+    None
+}
+
+/// 날짜 원문 → KST 자정 기준 DateTime
+pub fn parse_date(date_raw: &str) -> Option<DateTime<FixedOffset>> {
+    let (yy, mm, dd) = extract_date_token(date_raw)?;
+    kst().with_ymd_and_hms(yy, mm, dd, 0, 0, 0).single()
+}
+
+/// 날짜 + "HH:MM(:SS)" 시각 원문 → KST DateTime. 시각이 없으면 자정
+pub fn parse_datetime(raw: &str) -> Option<DateTime<FixedOffset>> {
+    let date = parse_date(raw)?;
+    for token in raw.split(|c: char| !(c.is_ascii_digit() || c == ':')) {
+        let parts: Vec<_> = token.split(':').collect();
+        if parts.len() < 2 || parts.len() > 3 || parts.iter().any(|p| p.is_empty() || p.len() > 2) {
+            continue;
+        }
+        let nums: Vec<u32> = parts.iter().filter_map(|p| p.parse().ok()).collect();
+        let (h, m, s) = (nums[0], nums[1], nums.get(2).copied().unwrap_or(0));
+        if let Some(dt) = date
+            .with_hour(h)
+            .and_then(|d| d.with_minute(m))
+            .and_then(|d| d.with_second(s))
+        {
+            return Some(dt);
+        }
+    }
+    Some(date)
+}
+
+/// 공백을 접고 `max_chars`자에서 자른 평문 요약
+pub fn summarize(text: &str, max_chars: usize) -> String {
+    let collapsed = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if collapsed.chars().count() <= max_chars {
+        return collapsed;
+    }
+    let mut s: String = collapsed.chars().take(max_chars).collect();
+    s.push('…');
+    s
+}
+
+/// 본문 HTML → 평문 (비교/검색용). 블록 요소/줄바꿈마다 줄을 나누고 빈 줄은 버림
+pub fn html_text(html: &str) -> String {
+    let doc = Document::from(html);
+    let mut text = String::new();
+    for node in doc.nth(0).into_iter().flat_map(|root| root.descendants()) {
+        match node.name() {
+            Some("br" | "p" | "div" | "li" | "tr" | "h1" | "h2" | "h3" | "h4" | "table") => text.push('\n'),
+            Some(_) => {}
+            None => {
+                if let Some(t) = node.as_text() {
+                    text.push_str(t);
+                }
+            }
+        }
+    }
+    text.lines()
+        .map(|l| l.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|l| !l.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// 파일 이름/URL 확장자로 MIME 타입 추정
+pub fn mime_from_name(name: &str) -> Option<&'static str> {
+    let ext = name.rsplit('.').next()?.trim().to_lowercase();
+    Some(match ext.as_str() {
+        "hwp" => "application/x-hwp",
+        "hwpx" => "application/hwp+zip",
+        "pdf" => "application/pdf",
+        "doc" => "application/msword",
+        "docx" => "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+        "xls" => "application/vnd.ms-excel",
+        "xlsx" => "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+        "ppt" => "application/vnd.ms-powerpoint",
+        "pptx" => "application/vnd.openxmlformats-officedocument.presentationml.presentation",
+        "zip" => "application/zip",
+        "txt" => "text/plain",
+        "jpg" | "jpeg" => "image/jpeg",
+        "png" => "image/png",
+        "gif" => "image/gif",
+        _ => return None,
+    })
+}
+
+/// "(123KB)", "[1.5 MB]", "2,048 bytes" 같은 크기 표기에서 바이트 수 추출.
+/// 괄호 안이나 텍스트 전체가 `<숫자> <단위>` 꼴일 때만 읽음 ("B형 신청서.hwp" 같은 이름은 무시)
+pub fn parse_size(text: &str) -> Option<u64> {
+    let groups = text
+        .split(['(', '['])
+        .skip(1)
+        .filter_map(|g| g.split_once([')', ']']).map(|(inner, _)| inner));
+    groups.chain(std::iter::once(text)).find_map(size_token)
+}
+
+/// "1,536 KB" → 1572864. 숫자와 단위 외의 글자가 있으면 None
+fn size_token(token: &str) -> Option<u64> {
+    let token = token.trim();
+    let split = token
+        .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == ','))
+        .unwrap_or(token.len());
+    let (num, unit) = token.split_at(split);
+    let mul: u64 = match unit.trim_start().to_uppercase().as_str() {
+        "GB" => 1 << 30,
+        "MB" => 1 << 20,
+        "KB" => 1 << 10,
+        "B" | "BYTE" | "BYTES" => 1,
+        _ => return None,
+    };
+    let n = num.replace(',', "").parse::<f64>().ok()?;
+    Some((n * mul as f64).round() as u64)
+}
+
+/// 제목 앞의 "[말머리]"를 분류로 추출
+pub fn category_from_title(title: &str) -> Option<String> {
+    let rest = title.trim_start().strip_prefix('[')?;
+    let end = rest.find(']')?;
+    let cat = rest[..end].trim();
+    if cat.is_empty() {
+        None
+    } else {
+        Some(cat.to_string())
+    }
+}
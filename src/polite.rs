@@ -0,0 +1,291 @@
+// src/polite.rs
+// 예의 있게 긁기: 호스트별 robots.txt 준수, 요청 간격(초당 요청 수/crawl-delay), 동시 요청 수 제한
+//
+// 판단 결과는 `[POLITE]` 로그로 남겨, 학교 측에 어떻게 동작하는지 보여 줄 수 있게 합니다.
+
+use reqwest::Url;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+use crate::config::HttpConfig;
+
+/// robots.txt에서 우리를 찾을 때 쓰는 이름 (User-agent: univ-crawler)
+pub const ROBOTS_AGENT: &str = "univ-crawler";
+/// robots.txt 재확인 주기 (서버 모드에서 오래 떠 있을 때)
+const ROBOTS_TTL: Duration = Duration::from_secs(24 * 3600);
+/// crawl-delay 상한. 이보다 길게 적혀 있으면 이 값으로 줄임 (한 번 실행이 끝나지 않는 것을 막음)
+pub const MAX_CRAWL_DELAY: Duration = Duration::from_secs(60);
+
+/// 한 호스트의 robots.txt 중 우리에게 적용되는 규칙
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Robots {
+    /// (허용 여부, 경로 패턴). `*` 와일드카드와 끝의 `$` 지원
+    rules: Vec<(bool, String)>,
+    pub crawl_delay: Option<Duration>,
+}
+
+impl Robots {
+    /// `agent`에 해당하는 그룹(없으면 `*` 그룹)의 Allow/Disallow/Crawl-delay만 추림
+    pub fn parse(txt: &str, agent: &str) -> Self {
+        let agent = agent.to_lowercase();
+        let mut specific = Robots::default();
+        let mut wildcard = Robots::default();
+        let mut has_specific = false;
+
+        // 현재 그룹의 User-agent 목록. 규칙이 나온 뒤의 User-agent는 새 그룹 시작
+        let mut group_agents: Vec<String> = Vec::new();
+        let mut in_rules = false;
+        for line in txt.lines() {
+            let line = line.split('#').next().unwrap_or("").trim();
+            let Some((key, value)) = line.split_once(':') else { continue };
+            let (key, value) = (key.trim().to_lowercase(), value.trim());
+            if key == "user-agent" {
+                if in_rules {
+                    group_agents.clear();
+                    in_rules = false;
+                }
+                group_agents.push(value.to_lowercase());
+                continue;
+            }
+            in_rules = true;
+            let is_ours = group_agents.iter().any(|a| a != "*" && agent.starts_with(a.as_str()));
+            let is_wildcard = group_agents.iter().any(|a| a == "*");
+            if !is_ours && !is_wildcard {
+                continue;
+            }
+            has_specific |= is_ours;
+            let target = if is_ours { &mut specific } else { &mut wildcard };
+            match key.as_str() {
+                // 빈 Disallow는 "전부 허용"
+                "disallow" if !value.is_empty() => target.rules.push((false, value.to_string())),
+                "allow" if !value.is_empty() => target.rules.push((true, value.to_string())),
+                "crawl-delay" => target.crawl_delay = parse_crawl_delay(value),
+                _ => {}
+            }
+        }
+        if has_specific {
+            specific
+        } else {
+            wildcard
+        }
+    }
+
+    /// 경로(+쿼리)를 가져가도 되는지. 가장 긴 패턴이 이기고, 길이가 같으면 Allow
+    pub fn allows(&self, path: &str) -> bool {
+        self.rules
+            .iter()
+            .filter(|(_, pattern)| pattern_matches(pattern, path))
+            .max_by_key(|(allow, pattern)| (pattern.len(), *allow))
+            .is_none_or(|(allow, _)| *allow)
+    }
+
+    pub fn rule_count(&self) -> usize {
+        self.rules.len()
+    }
+}
+
+/// 음수·NaN·무한대 등 잘못된 값은 무시하고, 너무 긴 값은 [`MAX_CRAWL_DELAY`]로 줄임
+fn parse_crawl_delay(value: &str) -> Option<Duration> {
+    let secs = value.parse::<f64>().ok().filter(|v| v.is_finite() && *v >= 0.0)?;
+    match Duration::try_from_secs_f64(secs) {
+        Ok(delay) if delay <= MAX_CRAWL_DELAY => Some(delay),
+        _ => {
+            eprintln!(
+                "[POLITE] robots.txt crawl-delay {value}초는 너무 길어 {}초로 줄입니다.",
+                MAX_CRAWL_DELAY.as_secs()
+            );
+            Some(MAX_CRAWL_DELAY)
+        }
+    }
+}
+
+fn pattern_matches(pattern: &str, path: &str) -> bool {
+    let anchored = pattern.ends_with('$');
+    let pattern = pattern.trim_end_matches('$');
+    let parts: Vec<&str> = pattern.split('*').collect();
+    let mut pos = 0;
+    for (i, part) in parts.iter().enumerate() {
+        if i == 0 {
+            if !path.starts_with(part) {
+                return false;
+            }
+            pos = part.len();
+        } else if anchored && i == parts.len() - 1 {
+            return path.len() >= pos + part.len() && path.ends_with(part);
+        } else {
+            match path[pos..].find(part) {
+                Some(offset) => pos += offset + part.len(),
+                None => return false,
+            }
+        }
+    }
+    !anchored || pos == path.len()
+}
+
+/// 호스트별 집계 (실행 요약 로그용)
+#[derive(Debug, Clone)]
+pub struct HostStats {
+    pub host: String,
+    pub requests: u32,
+    pub waited: Duration,
+    pub disallowed: u32,
+    pub crawl_delay: Option<Duration>,
+}
+
+struct HostState {
+    next_slot: Instant,
+    crawl_delay: Option<Duration>,
+    requests: u32,
+    waited: Duration,
+    disallowed: u32,
+}
+
+struct Host {
+    permits: Arc<Semaphore>,
+    state: Mutex<HostState>,
+    /// (읽은 시각, 규칙). robots.txt를 동시에 여러 번 받지 않도록 비동기 뮤텍스
+    robots: tokio::sync::Mutex<Option<(Instant, Arc<Robots>)>>,
+}
+
+pub struct Politeness {
+    respect_robots: bool,
+    /// 초당 요청 수로 정한 최소 간격 (crawl-delay가 더 길면 그쪽)
+    min_interval: Duration,
+    host_concurrency: usize,
+    hosts: Mutex<HashMap<String, Arc<Host>>>,
+}
+
+impl Politeness {
+    pub fn new(config: &HttpConfig) -> Self {
+        let min_interval = if config.host_rps > 0.0 {
+            Duration::from_secs_f64(1.0 / config.host_rps)
+        } else {
+            Duration::ZERO
+        };
+        Politeness {
+            respect_robots: config.respect_robots,
+            min_interval,
+            host_concurrency: config.host_concurrency.max(1),
+            hosts: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn host(&self, key: &str) -> Arc<Host> {
+        let mut hosts = self.hosts.lock().unwrap();
+        hosts
+            .entry(key.to_string())
+            .or_insert_with(|| {
+                Arc::new(Host {
+                    permits: Arc::new(Semaphore::new(self.host_concurrency)),
+                    state: Mutex::new(HostState {
+                        next_slot: Instant::now(),
+                        crawl_delay: None,
+                        requests: 0,
+                        waited: Duration::ZERO,
+                        disallowed: 0,
+                    }),
+                    robots: tokio::sync::Mutex::new(None),
+                })
+            })
+            .clone()
+    }
+
+    /// robots.txt가 이 URL을 허용하는지. 호스트별로 처음 한 번(이후 하루마다) `fetch`로
+    /// robots.txt(`/robots.txt` 주소를 넘겨줌)를 읽음. 받지 못했으면 캐시하지 않고 그 오류를 돌려주므로
+    /// 다음 요청이 다시 받아 봄
+    pub async fn allowed<F, Fut, E>(&self, url: &Url, fetch: F) -> Result<bool, E>
+    where
+        F: FnOnce(Url) -> Fut,
+        Fut: Future<Output = Result<Robots, E>>,
+    {
+        if !self.respect_robots {
+            return Ok(true);
+        }
+        let Some(key) = host_key(url) else { return Ok(true) };
+        let Ok(robots_url) = url.join("/robots.txt") else { return Ok(true) };
+        let host = self.host(&key);
+        let robots = {
+            let mut cached = host.robots.lock().await;
+            match cached.as_ref() {
+                Some((at, robots)) if at.elapsed() < ROBOTS_TTL => robots.clone(),
+                _ => {
+                    let robots = Arc::new(fetch(robots_url).await?);
+                    eprintln!(
+                        "[POLITE] {key}: robots.txt 적용 (규칙 {}개, crawl-delay {})",
+                        robots.rule_count(),
+                        robots
+                            .crawl_delay
+                            .map_or("없음".to_string(), |d| format!("{:.1}초", d.as_secs_f32()))
+                    );
+                    host.state.lock().unwrap().crawl_delay = robots.crawl_delay;
+                    *cached = Some((Instant::now(), robots.clone()));
+                    robots
+                }
+            }
+        };
+        let path = match url.query() {
+            Some(q) => format!("{}?{}", url.path(), q),
+            None => url.path().to_string(),
+        };
+        let allowed = robots.allows(&path);
+        if !allowed {
+            host.state.lock().unwrap().disallowed += 1;
+            eprintln!("[POLITE] robots.txt가 금지한 주소라 요청하지 않습니다: {url}");
+        }
+        Ok(allowed)
+    }
+
+    /// 호스트 동시 요청 슬롯을 잡고, 직전 요청과의 간격이 찰 때까지 기다림.
+    /// 돌려받은 허가는 응답 본문을 다 읽을 때까지 들고 있어야 함
+    pub async fn acquire(&self, url: &Url) -> Option<OwnedSemaphorePermit> {
+        let key = host_key(url)?;
+        let host = self.host(&key);
+        let permit = host.permits.clone().acquire_owned().await.ok()?;
+        let (at, delay) = {
+            let mut state = host.state.lock().unwrap();
+            let interval = state.crawl_delay.map_or(self.min_interval, |d| d.max(self.min_interval));
+            let now = Instant::now();
+            let at = state.next_slot.max(now);
+            state.next_slot = at + interval;
+            state.requests += 1;
+            state.waited += at - now;
+            (at, at - now)
+        };
+        if delay >= Duration::from_secs(5) {
+            eprintln!("[POLITE] {key}: 요청 간격을 지키려고 {:.1}초 대기", delay.as_secs_f32());
+        }
+        tokio::time::sleep_until(at.into()).await;
+        Some(permit)
+    }
+
+    /// 요청한 적 있는 호스트별 집계 (호스트 이름 순)
+    pub fn stats(&self) -> Vec<HostStats> {
+        let hosts = self.hosts.lock().unwrap();
+        let mut stats: Vec<_> = hosts
+            .iter()
+            .map(|(key, host)| {
+                let s = host.state.lock().unwrap();
+                HostStats {
+                    host: key.clone(),
+                    requests: s.requests,
+                    waited: s.waited,
+                    disallowed: s.disallowed,
+                    crawl_delay: s.crawl_delay,
+                }
+            })
+            .collect();
+        stats.sort_by(|a, b| a.host.cmp(&b.host));
+        stats
+    }
+}
+
+fn host_key(url: &Url) -> Option<String> {
+    let host = url.host_str()?;
+    Some(match url.port() {
+        Some(port) => format!("{host}:{port}"),
+        None => host.to_string(),
+    })
+}
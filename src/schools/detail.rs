@@ -0,0 +1,198 @@
+// src/schools/detail.rs
+// 상세 페이지 공통 처리: 본문/작성자/게시 시각/첨부파일 추출
+
+use futures::stream::{self, StreamExt};
+use reqwest::Url;
+use select::document::Document;
+use std::future::Future;
+use select::node::Node;
+use select::predicate::{Class, Name, Predicate};
+
+use crate::error::ScrapeError;
+use crate::model::{self, Attachment, Notice, NoticeDetail};
+
+/// 요약 길이 (글자 수)
+const SUMMARY_CHARS: usize = 200;
+
+/// 목록의 각 공지에 상세 페이지 결과를 채움 (최대 `concurrency`개 동시 요청).
+/// 실패한 글은 경고만 남기고 목록 정보 그대로 둠
+pub async fn fill_details<F, Fut>(notices: &mut [Notice], concurrency: usize, fetch: F)
+where
+    F: Fn(String) -> Fut,
+    Fut: Future<Output = Result<NoticeDetail, ScrapeError>>,
+{
+    let targets: Vec<(usize, String)> = notices
+        .iter()
+        .enumerate()
+        .filter(|(_, n)| !n.url.starts_with("javascript:"))
+        .map(|(i, n)| (i, n.url.clone()))
+        .collect();
+
+    let results: Vec<(usize, Result<NoticeDetail, ScrapeError>)> = stream::iter(targets)
+        .map(|(i, url)| {
+            let fut = fetch(url);
+            async move { (i, fut.await) }
+        })
+        .buffer_unordered(concurrency.max(1))
+        .collect()
+        .await;
+
+    for (i, res) in results {
+        match res {
+            Ok(detail) => notices[i].apply_detail(detail),
+            Err(e) => eprintln!("{e} — 상세 없이 진행합니다."),
+        }
+    }
+}
+
+/// 상세 페이지 HTML에서 공통 항목 추출
+///
+/// - `body_classes`: 본문 컨테이너 후보 class (앞에서부터 시도)
+/// - `site_root`: `page_url`을 해석할 수 없을 때 상대 링크의 기준
+/// - `page_url`: 상세 페이지 주소. 본문 속 링크/이미지와 첨부 링크(`?fileId=..`, `//cdn..` 등)를 이 주소 기준 절대 URL로
+pub fn parse_detail(html: &str, body_classes: &[&str], site_root: &str, page_url: &str) -> NoticeDetail {
+    let doc = Document::from(html);
+
+    let body = body_classes
+        .iter()
+        .find_map(|c| doc.find(Class(*c)).next());
+    let body_html = body
+        .as_ref()
+        .map(|b| absolutize_html(b.inner_html().trim(), site_root, page_url))
+        .filter(|s| !s.is_empty());
+    let summary = body
+        .as_ref()
+        .map(|b| model::summarize(&b.text(), SUMMARY_CHARS))
+        .filter(|s| !s.is_empty());
+
+    let author = labelled_value(&doc, &["작성자", "담당부서", "부서", "등록자", "글쓴이"]);
+    let posted_at = labelled_value(&doc, &["작성일", "등록일", "게시일", "날짜"])
+        .and_then(|v| model::parse_datetime(&v));
+
+    NoticeDetail {
+        body_html,
+        summary,
+        author,
+        posted_at,
+        attachments: parse_attachments(&doc, site_root, page_url),
+    }
+}
+
+/// 첨부파일 링크 수집: 파일 확장자로 끝나는 링크 텍스트, 또는 다운로드용 href
+pub fn parse_attachments(doc: &Document, site_root: &str, page_url: &str) -> Vec<Attachment> {
+    let mut out: Vec<Attachment> = Vec::new();
+    for a in doc.find(Name("a")) {
+        let Some(href) = a.attr("href").map(str::trim) else {
+            continue;
+        };
+        if href.is_empty() || href.starts_with('#') || href.starts_with("javascript:") {
+            continue;
+        }
+        let name = a.text().split_whitespace().collect::<Vec<_>>().join(" ");
+        let lower = href.to_lowercase();
+        let looks_like_file = model::mime_from_name(&name).is_some()
+            || ["download", "filedown", "file_down", "attach"].iter().any(|k| lower.contains(k));
+        if !looks_like_file || name.is_empty() {
+            continue;
+        }
+
+        let url = resolve_url(href, site_root, page_url);
+        if out.iter().any(|x| x.url == url) {
+            continue;
+        }
+        // 크기는 보통 링크 옆 "(123KB)" 형태로 부모 요소에 함께 있음
+        let size = a
+            .parent()
+            .and_then(|p| model::parse_size(&p.text().replace(&a.text(), "")));
+        out.push(Attachment::new(name, url, size));
+    }
+    out
+}
+
+/// 상세 페이지 기준 절대 URL (`/x`, `//cdn/x`, `?a=1`, `x.png` 모두). 해석할 수 없으면 그대로
+fn resolve_url(href: &str, site_root: &str, page_url: &str) -> String {
+    Url::parse(page_url)
+        .or_else(|_| Url::parse(site_root))
+        .and_then(|base| base.join(href))
+        .map_or_else(|_| href.to_string(), String::from)
+}
+
+/// "작성자 : 학사팀" 또는 `<th>작성자</th><td>학사팀</td>` 형태의 값 찾기
+pub fn labelled_value(doc: &Document, labels: &[&str]) -> Option<String> {
+    let candidates = Name("th")
+        .or(Name("dt"))
+        .or(Name("span"))
+        .or(Name("strong"))
+        .or(Name("em"))
+        .or(Name("li"));
+    for node in doc.find(candidates) {
+        let text = node.text();
+        let text = text.trim();
+        let Some(label) = labels.iter().find(|l| text.starts_with(**l)) else {
+            continue;
+        };
+        // 같은 요소 안에 값이 붙어 있는 경우
+        let inline = text[label.len()..].trim().trim_start_matches(':').trim();
+        if !inline.is_empty() {
+            return Some(inline.to_string());
+        }
+        // 바로 다음 형제 요소(td/dd/span)에 값이 있는 경우
+        if let Some(value) = next_element(&node).map(|n| n.text().trim().to_string()) {
+            if !value.is_empty() {
+                return Some(value);
+            }
+        }
+    }
+    None
+}
+
+fn next_element<'a>(node: &Node<'a>) -> Option<Node<'a>> {
+    let mut cur = node.next();
+    while let Some(n) = cur {
+        if n.name().is_some() {
+            return Some(n);
+        }
+        cur = n.next();
+    }
+    None
+}
+
+/// 본문 속 `src`/`href` 값(큰따옴표·작은따옴표·따옴표 없음)을 상세 페이지 기준 절대 URL로.
+/// 이미 스킴이 있는 값(`https:`, `mailto:` 등)과 `#앵커`는 그대로 둠
+fn absolutize_html(html: &str, site_root: &str, page_url: &str) -> String {
+    // ASCII만 소문자로 바꿔 바이트 위치가 원문과 같게 유지
+    let lower = html.to_ascii_lowercase();
+    let mut out = String::with_capacity(html.len());
+    let mut copied = 0;
+    while let Some(value_at) = ["src=", "href="]
+        .iter()
+        .filter_map(|attr| find_attr(&lower, copied, attr).map(|at| at + attr.len()))
+        .min()
+    {
+        let quote = html[value_at..].chars().next().filter(|c| *c == '"' || *c == '\'');
+        let start = value_at + quote.map_or(0, char::len_utf8);
+        let len = match quote {
+            Some(q) => html[start..].find(q),
+            None => html[start..].find(|c: char| c.is_ascii_whitespace() || c == '>'),
+        };
+        let end = len.map_or(html.len(), |len| start + len);
+        let value = &html[start..end];
+        out.push_str(&html[copied..start]);
+        if value.is_empty() || value.starts_with('#') || Url::parse(value).is_ok() {
+            out.push_str(value);
+        } else {
+            out.push_str(&resolve_url(value, site_root, page_url));
+        }
+        copied = end;
+    }
+    out.push_str(&html[copied..]);
+    out
+}
+
+/// `from` 이후 처음 나오는 속성 `attr`(예: "src=")의 위치. 앞이 공백이어야 함 (`data-src=` 제외)
+fn find_attr(lower: &str, from: usize, attr: &str) -> Option<usize> {
+    lower[from..]
+        .match_indices(attr)
+        .map(|(i, _)| from + i)
+        .find(|&i| i > 0 && lower.as_bytes()[i - 1].is_ascii_whitespace())
+}
@@ -1,48 +1,35 @@
-use reqwest::blocking::Client;
-use reqwest::header::REFERER;
+use async_trait::async_trait;
 use select::document::Document;
 use select::predicate::{Class, Name, Predicate};
-use std::error::Error;
-use std::time::Duration;
-
-// 공통 Notice 타입 재사용
-use crate::schools::sookmyung;
-pub type Notice = sookmyung::Notice;
-
-/// "YYYY.MM.DD / YYYY-MM-DD / YYYY/MM/DD" 형태 감지
-fn is_date_like(s: &str) -> bool {
-    let t = s.trim().trim_end_matches('.');
-    let sep = if t.contains('.') { '.' } else if t.contains('-') { '-' } else if t.contains('/') { '/' } else { return false };
-    let parts: Vec<_> = t.split(sep).map(|x| x.trim()).collect();
-    if parts.len() != 3 { return false; }
-    let (y, m, d) = (parts[0], parts[1], parts[2]);
-    y.len() == 4 && y.chars().all(|c| c.is_ascii_digit())
-        && m.chars().all(|c| c.is_ascii_digit())
-        && d.chars().all(|c| c.is_ascii_digit())
-}
 
-/// 동덕여대 학사 공지 1페이지 수집
-pub fn fetch_notices() -> Result<Vec<Notice>, Box<dyn Error>> {
-    let client = Client::builder()
-        .user_agent("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/114.0.0.0 Safari/537.36")
-        .timeout(Duration::from_secs(10))
-        .build()?;
+use crate::error::{check_parsed, ScrapeError};
+use crate::health::ListStats;
+use crate::model::Notice;
+use crate::schools::{is_date_like, is_pinned_row, query_param, ChannelMeta, School};
 
-    let base_url = "https://www.dongduk.ac.kr/www/contents/kor-noti.do";
-    let url = format!("{base_url}?schM=list&page=1&viewCount=10");
+const SCHOOL: &str = "dongduk";
+const SITE_ROOT: &str = "https://www.dongduk.ac.kr";
 
-    let res = client.get(&url).header(REFERER, base_url).send()?;
-    if !res.status().is_success() {
-        eprintln!("[DONGDUK] HTTP {}", res.status());
-        return Ok(Vec::new());
-    }
-    let body = res.text()?;
-    let document = Document::from(body.as_str());
+const BASE_URL: &str = "https://www.dongduk.ac.kr/www/contents/kor-noti.do";
+
+/// 목록 페이지 주소 (page 파라미터로 페이지 이동)
+fn list_url(page: usize) -> String {
+    format!("{BASE_URL}?schM=list&page={page}&viewCount=10")
+}
+
+/// 목록 페이지 HTML → 공지 목록 (행/누락 수와 구조 지문은 `stats`에 누적)
+pub fn parse_list(body: &str, url: &str, stats: &mut ListStats) -> Result<Vec<Notice>, ScrapeError> {
+    let document = Document::from(body);
+    stats.begin_page(document.find(Class("board-basic")).next());
 
     let mut notices = Vec::new();
+    let mut rows = 0;
+    let mut first_failure = None;
 
     // 공지 1건 = ul.board-basic > li
-    for li in document.find(Class("board-basic").descendant(Name("li"))) {
+    for (i, li) in document.find(Class("board-basic").descendant(Name("li"))).enumerate() {
+        rows += 1;
+        stats.rows += 1;
         // 제목: dt > a.subTit
         let a = li
             .find(Name("dt").descendant(Name("a").and(Class("subTit"))))
@@ -51,7 +38,12 @@ pub fn fetch_notices() -> Result<Vec<Notice>, Box<dyn Error>> {
         let title = a
             .as_ref()
             .map(|n| n.text().trim().to_string())
-            .unwrap_or_else(|| "(제목 없음)".to_string());
+            .unwrap_or_default();
+        if title.is_empty() {
+            stats.missing_title += 1;
+            first_failure.get_or_insert((i + 1, "dt > a.subTit 제목 없음".to_string()));
+            continue;
+        }
 
         // 날짜: li 내부 텍스트 중 "날짜처럼 보이는" 것만 추출 (조회수/댓글수 등 제외)
         let mut date = String::new();
@@ -71,47 +63,57 @@ pub fn fetch_notices() -> Result<Vec<Notice>, Box<dyn Error>> {
         if date.is_empty() { date = "N/A".to_string(); }
 
         // 상세 URL: href의 schM=view 우선, 없으면 onclick="fn_goView('id', false, 'no', '')" 파싱
+        let mut article_id = None;
         let detail_url = if let Some(href) = find_view_href_in_li(&li) {
+            article_id = query_param(&href, "id");
             href
         } else {
             let onclick = a.as_ref().and_then(|n| n.attr("onclick")).unwrap_or("");
             if let Some((id, no)) = parse_fn_go_view(onclick) {
-                format!("{base_url}?schM=view&id={id}&etc1={no}")
+                let url = format!("{BASE_URL}?schM=view&id={id}&etc1={no}");
+                article_id = Some(id);
+                url
             } else {
                 // 폴백(디버깅용)
                 format!("javascript:{onclick}")
             }
         };
 
-        if !title.is_empty() {
-            notices.push(Notice { title, date, url: detail_url });
-        }
+        let mut notice = Notice::new(SCHOOL, title, date, detail_url);
+        notice.id = article_id;
+        // 상단 고정: li.notice 또는 "공지" 배지
+        notice.pinned = is_pinned_row(&li, &["notice", "noti", "icon-notice"]);
+        notices.push(notice);
     }
 
-    Ok(notices)
+    check_parsed(SCHOOL, url, rows, first_failure, notices)
 }
 
-// RSS 생성
-use rss::{ChannelBuilder, ItemBuilder};
-
-pub fn create_rss(notices: &[Notice]) -> rss::Channel {
-    let items = notices
-        .iter()
-        .map(|n| {
-            ItemBuilder::default()
-                .title(n.title.clone())
-                .link(n.url.clone())
-                .pub_date(n.date.clone()) // RFC 변환은 main에서 normalize
-                .build()
-        })
-        .collect::<Vec<_>>();
-
-    ChannelBuilder::default()
-        .title("동덕여자대학교 학사 공지 RSS")
-        .link("https://www.dongduk.ac.kr/www/contents/kor-noti.do?schM=list")
-        .description("동덕여대 학사 공지 RSS 피드")
-        .items(items)
-        .build()
+// RSS 채널 정보
+pub const CHANNEL: ChannelMeta = ChannelMeta {
+    title: "동덕여자대학교 학사 공지 RSS",
+    link: "https://www.dongduk.ac.kr/www/contents/kor-noti.do?schM=list",
+    description: "동덕여대 학사 공지 RSS 피드",
+};
+
+/* ─── 레지스트리 등록 ─── */
+
+pub struct Dongduk;
+
+#[async_trait]
+impl School for Dongduk {
+    fn key(&self) -> &'static str { SCHOOL }
+    fn aliases(&self) -> &'static [&'static str] { &["dd", "동덕"] }
+    fn display_name(&self) -> &'static str { "동덕여자대학교" }
+    fn base_url(&self) -> &'static str { SITE_ROOT }
+    fn channel(&self) -> ChannelMeta { CHANNEL }
+    fn list_base(&self) -> &'static str { BASE_URL }
+    fn list_url(&self, page: usize) -> String { list_url(page) }
+    fn parse_list(&self, body: &str, url: &str, stats: &mut ListStats) -> Result<Vec<Notice>, ScrapeError> {
+        parse_list(body, url, stats)
+    }
+    // 상세 페이지(schM=view) 본문
+    fn detail_classes(&self) -> &'static [&'static str] { &["view-con", "board-view-con", "bbs-view-content", "board-view"] }
 }
 
 /* ─── 유틸 ─── */
@@ -131,7 +133,8 @@ fn find_view_href_in_li(li: &select::node::Node) -> Option<String> {
     None
 }
 
-fn parse_fn_go_view(onclick: &str) -> Option<(String, String)> {
+/// onclick="fn_goView('id', .., 'no', ..)" → (id, no)
+pub fn parse_fn_go_view(onclick: &str) -> Option<(String, String)> {
     // 예: fn_goView('90378', false, '8901', '')
     let marker = "fn_goView(";
     let start = onclick.find(marker)? + marker.len();
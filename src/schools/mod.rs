@@ -2,3 +2,378 @@
 pub mod sookmyung;
 pub mod dongduk;
 pub mod seoul;
+pub mod detail;
+
+use async_trait::async_trait;
+use rss::extension::dublincore::DublinCoreExtensionBuilder;
+use rss::{CategoryBuilder, ChannelBuilder, EnclosureBuilder, GuidBuilder, ItemBuilder};
+use atom_syndication::{
+    CategoryBuilder as AtomCategoryBuilder, ContentBuilder, EntryBuilder, FeedBuilder, LinkBuilder, PersonBuilder, Text,
+};
+use select::node::Node;
+use std::collections::HashSet;
+use std::future::Future;
+use std::sync::Mutex;
+
+pub use crate::error::ScrapeError;
+pub use crate::health::ListStats;
+pub use crate::http::HttpClient;
+use crate::json_feed::{self, JsonFeed};
+pub use crate::model::Notice;
+use crate::model::NoticeDetail;
+use detail::{fill_details, parse_detail};
+
+/// RSS 채널 메타데이터 (제목/링크/설명)
+pub struct ChannelMeta {
+    pub title: &'static str,
+    pub link: &'static str,
+    pub description: &'static str,
+}
+
+/// 목록 수집 범위
+#[derive(Debug, Clone)]
+pub struct FetchOptions {
+    /// 최대 페이지 수 (1 = 첫 페이지만)
+    pub max_pages: usize,
+    /// 최대 공지 수
+    pub max_items: usize,
+    /// 이전 실행에서 이미 본 공지의 GUID/URL. 페이지의 마지막 행이 여기 있으면 다음 페이지로 넘어가지 않음
+    pub seen: HashSet<String>,
+    /// 상세 페이지까지 읽어 본문/요약/작성자/게시 시각 채우기
+    pub details: bool,
+    /// 상세 페이지 동시 요청 수
+    pub concurrency: usize,
+}
+
+impl Default for FetchOptions {
+    fn default() -> Self {
+        FetchOptions {
+            max_pages: 1,
+            max_items: 100,
+            seen: HashSet::new(),
+            details: false,
+            concurrency: 4,
+        }
+    }
+}
+
+/// 학교 1곳의 크롤러. 새 학교는 모듈 하나 + `REGISTRY` 항목 하나로 추가합니다.
+/// 학교 모듈은 목록 주소와 행 파싱, 상세 본문 선택자만 정하고, 페이지 이동/상세 수집은 기본 구현을 씁니다.
+#[async_trait]
+pub trait School: Sync {
+    /// 정규 키 — URL 경로와 출력 디렉터리 이름 (예: "sookmyung")
+    fn key(&self) -> &'static str;
+    /// 키 외에 받아들이는 별칭 (예: "sm", "숙명")
+    fn aliases(&self) -> &'static [&'static str];
+    /// 학교 이름 (예: "숙명여자대학교")
+    fn display_name(&self) -> &'static str;
+    /// 상대 URL을 절대 URL로 바꿀 때 쓰는 사이트 루트
+    fn base_url(&self) -> &'static str;
+    fn channel(&self) -> ChannelMeta;
+    /// 목록 게시판 주소 (목록/상세 요청의 Referer)
+    fn list_base(&self) -> &'static str;
+    /// `page`번째(1부터) 목록 페이지 주소
+    fn list_url(&self, page: usize) -> String;
+    /// 목록 페이지 HTML → 공지 목록 (행/누락 수와 구조 지문은 `stats`에 누적)
+    fn parse_list(&self, body: &str, url: &str, stats: &mut ListStats) -> Result<Vec<Notice>, ScrapeError>;
+    /// 상세 페이지 본문 컨테이너 후보 class (앞에서부터 시도)
+    fn detail_classes(&self) -> &'static [&'static str];
+
+    /// 목록(+상세) 수집. 헬스 리포트용 목록 통계를 함께 돌려줌
+    async fn fetch_with_stats(&self, client: &HttpClient, opts: &FetchOptions) -> (Result<Vec<Notice>, ScrapeError>, ListStats) {
+        let stats = Mutex::new(ListStats::default());
+        let fetch_page = |page: usize| {
+            let stats = &stats;
+            async move {
+                let url = self.list_url(page);
+                let body = client.get_list_html(self.key(), &url, self.list_base()).await?;
+                self.parse_list(&body, &url, &mut stats.lock().unwrap())
+            }
+        };
+        let result = match paginate(opts, fetch_page).await {
+            Ok(mut notices) => {
+                if opts.details {
+                    fill_details(&mut notices, opts.concurrency, |url| fetch_detail(self, client, url)).await;
+                }
+                Ok(notices)
+            }
+            Err(e) => Err(e),
+        };
+        (result, stats.into_inner().unwrap())
+    }
+
+    async fn fetch_notices(&self, client: &HttpClient, opts: &FetchOptions) -> Result<Vec<Notice>, ScrapeError> {
+        self.fetch_with_stats(client, opts).await.0
+    }
+
+    fn matches(&self, name: &str) -> bool {
+        let name = name.to_lowercase();
+        self.key() == name || self.aliases().iter().any(|a| *a == name)
+    }
+
+    fn create_rss(&self, notices: &[Notice]) -> rss::Channel {
+        build_rss(&self.channel(), notices)
+    }
+
+    /// `public_url` 아래 이 학교 피드 파일의 주소 (`{public_url}/school-rss/{key}/{file}`)
+    fn feed_url(&self, public_url: &str, file: &str) -> String {
+        format!("{public_url}/school-rss/{}/{file}", self.key())
+    }
+
+    /// `public_url`은 피드 자신의 주소(`rel="self"`)를 만들 때 씀
+    fn create_atom(&self, notices: &[Notice], public_url: &str) -> atom_syndication::Feed {
+        build_atom(&self.channel(), self.display_name(), &self.feed_url(public_url, "atom.xml"), notices)
+    }
+
+    /// `public_url`은 피드 자신의 주소(`feed_url`)를 만들 때 씀
+    fn create_json_feed(&self, notices: &[Notice], public_url: &str) -> JsonFeed {
+        json_feed::build_json_feed(&self.channel(), self.display_name(), &self.feed_url(public_url, "feed.json"), notices)
+    }
+}
+
+/// 등록된 학교 목록 (출력 순서 = 등록 순서)
+pub static REGISTRY: &[&dyn School] = &[&sookmyung::Sookmyung, &dongduk::Dongduk, &seoul::Seoul];
+
+pub fn all() -> &'static [&'static dyn School] {
+    REGISTRY
+}
+
+/// 키 또는 별칭으로 학교 찾기
+pub fn find(name: &str) -> Option<&'static dyn School> {
+    REGISTRY.iter().copied().find(|s| s.matches(name))
+}
+
+/// 상세 페이지 본문/작성자/게시 시각/첨부
+async fn fetch_detail<S: School + ?Sized>(school: &S, client: &HttpClient, url: String) -> Result<NoticeDetail, ScrapeError> {
+    let html = client.get_html(school.key(), &url, school.list_base()).await?;
+    Ok(parse_detail(&html, school.detail_classes(), school.base_url(), &url))
+}
+
+/// "YYYY.MM.DD / YYYY-MM-DD / YYYY/MM/DD" 형태 감지 (목록 칸 중 조회수/댓글수가 아닌 날짜 칸 찾기)
+pub fn is_date_like(s: &str) -> bool {
+    let t = s.trim().trim_end_matches('.');
+    let sep = if t.contains('.') { '.' } else if t.contains('-') { '-' } else if t.contains('/') { '/' } else { return false };
+    let parts: Vec<_> = t.split(sep).map(|x| x.trim()).collect();
+    if parts.len() != 3 { return false; }
+    let (y, m, d) = (parts[0], parts[1], parts[2]);
+    y.len() == 4 && y.chars().all(|c| c.is_ascii_digit())
+        && m.chars().all(|c| c.is_ascii_digit())
+        && d.chars().all(|c| c.is_ascii_digit())
+}
+
+/// URL 쿼리 문자열에서 `key` 값 꺼내기 (`&amp;`로 이스케이프된 href도 처리)
+pub fn query_param(url: &str, key: &str) -> Option<String> {
+    let query = url.split_once('?')?.1;
+    query
+        .split('&')
+        .map(|kv| kv.trim_start_matches("amp;"))
+        .filter_map(|kv| kv.split_once('='))
+        .find(|(k, _)| *k == key)
+        .map(|(_, v)| v.to_string())
+        .filter(|v| !v.is_empty())
+}
+
+/// 상단 고정("공지") 행 판별: 행 자체나 배지의 class가 `classes` 중 하나이거나,
+/// 번호 칸 등에 "공지" 글자/아이콘(alt)이 있는 경우
+pub fn is_pinned_row(row: &Node, classes: &[&str]) -> bool {
+    let has_class = |n: &Node| {
+        n.attr("class")
+            .is_some_and(|c| c.split_whitespace().any(|c| classes.contains(&c)))
+    };
+    if has_class(row) {
+        return true;
+    }
+    row.descendants().any(|n| match n.name() {
+        Some("img") => n.attr("alt").is_some_and(|alt| alt.contains("공지")),
+        Some("td" | "span" | "em" | "strong" | "i") => has_class(&n) || n.text().trim() == "공지",
+        Some(_) => has_class(&n),
+        None => false,
+    })
+}
+
+/// 페이지 단위 수집 공통 루프. `fetch_page(n)`은 1부터 시작하는 n번째 목록 페이지를 읽습니다.
+///
+/// 다음 중 하나면 멈춥니다: `max_pages`/`max_items` 도달, 페이지 마지막 행(가장 오래된 글)이
+/// 이미 본 공지, 새 공지가 없는 페이지, 2페이지 이후의 오류(경고 후 지금까지 모은 것 반환).
+/// 상단 고정 글은 매 페이지 맨 위에 반복되므로 마지막 행만 보고 판단합니다.
+pub async fn paginate<F, Fut>(opts: &FetchOptions, mut fetch_page: F) -> Result<Vec<Notice>, ScrapeError>
+where
+    F: FnMut(usize) -> Fut,
+    Fut: Future<Output = Result<Vec<Notice>, ScrapeError>>,
+{
+    let mut notices: Vec<Notice> = Vec::new();
+    let mut guids = HashSet::new();
+
+    for page in 1..=opts.max_pages.max(1) {
+        let rows = match fetch_page(page).await {
+            Ok(rows) => rows,
+            Err(e) if page == 1 => return Err(e),
+            // 마지막 페이지를 지나면 빈 목록이 옴
+            Err(ScrapeError::NoRows { .. }) => break,
+            Err(e) => {
+                eprintln!("{e} — {page}페이지에서 수집을 멈춥니다.");
+                break;
+            }
+        };
+        let reached_seen = rows
+            .last()
+            .is_some_and(|n| opts.seen.contains(&n.guid()) || opts.seen.contains(&n.url));
+
+        let before = notices.len();
+        for n in rows {
+            if guids.insert(n.guid()) {
+                notices.push(n);
+            }
+        }
+        if notices.len() >= opts.max_items {
+            notices.truncate(opts.max_items);
+            break;
+        }
+        if reached_seen || notices.len() == before {
+            break;
+        }
+    }
+
+    Ok(notices)
+}
+
+/// 공통 RSS 채널 생성 (날짜 파싱/절대 URL 보장은 main에서 normalize)
+pub fn build_rss(meta: &ChannelMeta, notices: &[Notice]) -> rss::Channel {
+    let items = notices
+        .iter()
+        .map(|n| {
+            let categories = n
+                .category
+                .iter()
+                .map(|c| CategoryBuilder::default().name(c.clone()).build())
+                .collect::<Vec<_>>();
+            // 작성자는 이메일이 아니므로 <author> 대신 dc:creator
+            let dc = n.author.as_ref().map(|a| {
+                DublinCoreExtensionBuilder::default()
+                    .creators(vec![a.clone()])
+                    .build()
+            });
+            // 첨부 1개 → <enclosure>, 여러 개 → RSS 2.0은 enclosure를 하나만 허용하므로 설명에 목록으로
+            let (enclosure, description) = match n.attachments.as_slice() {
+                [] => (None, n.summary.clone()),
+                [a] => {
+                    let enc = EnclosureBuilder::default()
+                        .url(a.url.clone())
+                        .length(a.size.unwrap_or(0).to_string())
+                        .mime_type(a.mime.clone().unwrap_or_else(|| "application/octet-stream".into()))
+                        .build();
+                    (Some(enc), n.summary.clone())
+                }
+                many => {
+                    let mut desc = n.summary.clone().unwrap_or_default();
+                    desc.push_str(&attachment_list_html(many));
+                    (None, Some(desc))
+                }
+            };
+            ItemBuilder::default()
+                .title(n.title.clone())
+                .link(n.url.clone())
+                .guid(GuidBuilder::default().value(n.guid()).permalink(false).build())
+                .pub_date(n.pub_date_rfc2822())
+                .description(description)
+                .content(n.body_html.clone())
+                .enclosure(enclosure)
+                .categories(categories)
+                .dublin_core_ext(dc)
+                .build()
+        })
+        .collect::<Vec<_>>();
+
+    ChannelBuilder::default()
+        .title(meta.title)
+        .link(meta.link)
+        .description(meta.description)
+        .items(items)
+        .build()
+}
+
+/// 공통 Atom 1.0 피드 생성. 글 id는 GUID 기반 URN이라 URL이 바뀌어도 그대로이고,
+/// `updated`는 저장소가 기록한 마지막 수정 시각(수정된 적 없으면 게시일)
+pub fn build_atom(meta: &ChannelMeta, author: &str, self_url: &str, notices: &[Notice]) -> atom_syndication::Feed {
+    let entries = notices
+        .iter()
+        .map(|n| {
+            let mut links = vec![LinkBuilder::default().href(n.url.clone()).build()];
+            // Atom은 enclosure 링크를 여러 개 허용하므로 첨부마다 하나씩
+            links.extend(n.attachments.iter().map(|a| {
+                LinkBuilder::default()
+                    .href(a.url.clone())
+                    .rel("enclosure")
+                    .title(a.name.clone())
+                    .mime_type(a.mime.clone())
+                    .length(a.size.map(|s| s.to_string()))
+                    .build()
+            }));
+            let content = n.body_html.as_ref().map(|html| {
+                ContentBuilder::default()
+                    .value(html.clone())
+                    .content_type("html".to_string())
+                    .build()
+            });
+            EntryBuilder::default()
+                .id(atom_id(&n.guid()))
+                .title(n.title.clone())
+                .updated(n.updated())
+                .published(n.pub_date())
+                .links(links)
+                .authors(n.author.iter().map(|a| PersonBuilder::default().name(a.clone()).build()).collect::<Vec<_>>())
+                .categories(n.category.iter().map(|c| AtomCategoryBuilder::default().term(c.clone()).build()).collect::<Vec<_>>())
+                .summary(n.summary.clone().map(Text::plain))
+                .content(content)
+                .build()
+        })
+        .collect::<Vec<_>>();
+
+    // 글이 없으면 지금 시각 (updated는 필수)
+    let updated = entries.iter().map(|e| e.updated).max().unwrap_or_else(crate::model::now_kst);
+    FeedBuilder::default()
+        .id(meta.link)
+        .title(meta.title)
+        .subtitle(Text::plain(meta.description))
+        .updated(updated)
+        .links(vec![
+            LinkBuilder::default().href(meta.link).build(),
+            LinkBuilder::default().href(self_url).rel("self").mime_type("application/atom+xml".to_string()).build(),
+        ])
+        // 작성자가 없는 글이 있어도 유효하도록 피드 작성자는 학교 이름
+        .authors(vec![PersonBuilder::default().name(author).build()])
+        .lang("ko".to_string())
+        .entries(entries)
+        .build()
+}
+
+/// GUID("seoul:506633")를 Atom id로 쓸 수 있는 URN으로. GUID가 URL이면 그대로
+fn atom_id(guid: &str) -> String {
+    if guid.starts_with("http://") || guid.starts_with("https://") {
+        guid.to_string()
+    } else {
+        format!("urn:univ-crawler:{guid}")
+    }
+}
+
+fn attachment_list_html(attachments: &[crate::model::Attachment]) -> String {
+    let mut html = String::from("<p>첨부파일</p><ul>");
+    for a in attachments {
+        let size = a.size_label().map(|s| format!(" ({s})")).unwrap_or_default();
+        html.push_str(&format!(
+            "<li><a href=\"{}\">{}</a>{}</li>",
+            escape_html(&a.url),
+            escape_html(&a.name),
+            size
+        ));
+    }
+    html.push_str("</ul>");
+    html
+}
+
+pub fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -1,49 +1,36 @@
-use reqwest::blocking::Client;
-use reqwest::header::REFERER;
+use async_trait::async_trait;
 use select::document::Document;
 use select::predicate::{Class, Name, Predicate};
-use std::error::Error;
-use std::time::Duration;
-
-// 공통 Notice 타입 재사용
-pub type Notice = crate::schools::sookmyung::Notice;
-
-/// "YYYY.MM.DD / YYYY-MM-DD / YYYY/MM/DD" 형태 감지
-fn is_date_like(s: &str) -> bool {
-    let t = s.trim().trim_end_matches('.');
-    let sep = if t.contains('.') { '.' } else if t.contains('-') { '-' } else if t.contains('/') { '/' } else { return false };
-    let parts: Vec<_> = t.split(sep).map(|x| x.trim()).collect();
-    if parts.len() != 3 { return false; }
-    let (y, m, d) = (parts[0], parts[1], parts[2]);
-    y.len() == 4 && y.chars().all(|c| c.is_ascii_digit())
-        && m.chars().all(|c| c.is_ascii_digit())
-        && d.chars().all(|c| c.is_ascii_digit())
+
+use crate::error::{check_parsed, ScrapeError};
+use crate::health::ListStats;
+use crate::model::Notice;
+use crate::schools::{is_date_like, is_pinned_row, query_param, ChannelMeta, School};
+
+const SCHOOL: &str = "seoul";
+const SITE_ROOT: &str = "https://www.swu.ac.kr";
+
+// iframe이 로드하는 실제 목록
+const LIST_BASE: &str = "https://www.swu.ac.kr/front/boardlist.do";
+
+/// 목록 페이지 주소 (currentPage 파라미터로 페이지 이동, 학사공지 bbsConfigFK=4)
+fn list_url(page: usize) -> String {
+    format!("{LIST_BASE}?currentPage={page}&menuGubun=1&siteGubun=1&bbsConfigFK=4&searchField=ALL&searchValue=&searchLowItem=ALL")
 }
 
-/// 서울여대 학사 공지 1페이지 수집
-pub fn fetch_notices() -> Result<Vec<Notice>, Box<dyn Error>> {
-    let client = Client::builder()
-        .user_agent("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/114.0.0.0 Safari/537.36")
-        .timeout(Duration::from_secs(10))
-        .build()?;
-
-    // iframe이 로드하는 실제 목록
-    let list_base = "https://www.swu.ac.kr/front/boardlist.do";
-    // 학사공지 bbsConfigFK=4
-    let url = format!("{list_base}?currentPage=1&menuGubun=1&siteGubun=1&bbsConfigFK=4&searchField=ALL&searchValue=&searchLowItem=ALL");
-
-    let res = client.get(&url).header(REFERER, list_base).send()?;
-    if !res.status().is_success() {
-        eprintln!("[SWU] HTTP {}", res.status());
-        return Ok(Vec::new());
-    }
-    let body = res.text()?;
-    let document = Document::from(body.as_str());
+/// 목록 페이지 HTML → 공지 목록 (행/누락 수와 구조 지문은 `stats`에 누적)
+pub fn parse_list(body: &str, url: &str, stats: &mut ListStats) -> Result<Vec<Notice>, ScrapeError> {
+    let document = Document::from(body);
+    stats.begin_page(document.find(Name("table").descendant(Name("tbody"))).next());
 
     let mut notices = Vec::new();
+    let mut rows = 0;
+    let mut first_failure = None;
 
     // 행: table > tbody > tr
-    for tr in document.find(Name("table").descendant(Name("tbody")).descendant(Name("tr"))) {
+    for (i, tr) in document.find(Name("table").descendant(Name("tbody")).descendant(Name("tr"))).enumerate() {
+        rows += 1;
+        stats.rows += 1;
         // 제목 a: td.title > div > a
         let a = tr
             .find(
@@ -57,8 +44,12 @@ pub fn fetch_notices() -> Result<Vec<Notice>, Box<dyn Error>> {
         let title = a
             .as_ref()
             .map(|n| n.text().trim().to_string())
-            .unwrap_or_else(|| "(제목 없음)".to_string());
-        if title.is_empty() { continue; }
+            .unwrap_or_default();
+        if title.is_empty() {
+            stats.missing_title += 1;
+            first_failure.get_or_insert((i + 1, "td.title > div > a 제목 없음".to_string()));
+            continue;
+        }
 
         // 날짜: 행 내부 텍스트 중 "날짜처럼 보이는" 것만 추출 (조회수/댓글수 제외)
         let mut date = String::new();
@@ -70,16 +61,20 @@ pub fn fetch_notices() -> Result<Vec<Notice>, Box<dyn Error>> {
 
         // 상세 URL: onclick="boardMove('/front/boardview.do','<pkid>')" → GET 파라미터로 전개
         // GET 링크: /front/boardview.do?pkid=...&menuGubun=1&siteGubun=1&bbsConfigFK=4
+        let mut article_id = None;
         let detail_url = if let Some(onclick) = a.as_ref().and_then(|n| n.attr("onclick")) {
             if let Some((path, pkid)) = parse_board_move(onclick) {
-                format!(
+                let url = format!(
                     "https://www.swu.ac.kr{}?pkid={}&menuGubun=1&siteGubun=1&bbsConfigFK=4",
                     path, pkid
-                )
+                );
+                article_id = Some(pkid);
+                url
             } else {
                 format!("javascript:{onclick}")
             }
         } else if let Some(href) = a.as_ref().and_then(|n| n.attr("href")) {
+            article_id = query_param(href, "pkid");
             if href.starts_with("http") {
                 href.to_string()
             } else {
@@ -87,45 +82,52 @@ pub fn fetch_notices() -> Result<Vec<Notice>, Box<dyn Error>> {
                 format!("https://www.swu.ac.kr{}", href)
             }
         } else {
+            stats.missing_url += 1;
+            first_failure.get_or_insert((i + 1, "제목 링크에 onclick/href 없음".to_string()));
             continue;
         };
 
-        notices.push(Notice { title, date, url: detail_url });
+        let mut notice = Notice::new(SCHOOL, title, date, detail_url);
+        notice.id = article_id;
+        // 상단 고정: tr.notice 또는 번호 칸의 "공지" 글자/아이콘
+        notice.pinned = is_pinned_row(&tr, &["notice", "noti", "ico_notice"]);
+        notices.push(notice);
     }
 
-    if notices.is_empty() {
-        eprintln!("[SWU] parsed 0 notices — 페이지 구조를 다시 확인하세요.");
-    }
-
-    Ok(notices)
+    check_parsed(SCHOOL, url, rows, first_failure, notices)
 }
 
-// RSS 생성
-use rss::{ChannelBuilder, ItemBuilder};
-
-pub fn create_rss(notices: &[Notice]) -> rss::Channel {
-    let items = notices
-        .iter()
-        .map(|n| {
-            ItemBuilder::default()
-                .title(n.title.clone())
-                .link(n.url.clone())
-                .pub_date(n.date.clone()) // RFC 변환은 main에서 normalize
-                .build()
-        })
-        .collect::<Vec<_>>();
-
-    ChannelBuilder::default()
-        .title("서울여자대학교 학사 공지 RSS")
-        .link("https://www.swu.ac.kr/www/noticea.html")
-        .description("서울여대 학사 공지 RSS 피드")
-        .items(items)
-        .build()
+// RSS 채널 정보
+pub const CHANNEL: ChannelMeta = ChannelMeta {
+    title: "서울여자대학교 학사 공지 RSS",
+    link: "https://www.swu.ac.kr/www/noticea.html",
+    description: "서울여대 학사 공지 RSS 피드",
+};
+
+/* ─── 레지스트리 등록 ─── */
+
+pub struct Seoul;
+
+#[async_trait]
+impl School for Seoul {
+    fn key(&self) -> &'static str { SCHOOL }
+    fn aliases(&self) -> &'static [&'static str] { &["swu", "서울"] }
+    fn display_name(&self) -> &'static str { "서울여자대학교" }
+    fn base_url(&self) -> &'static str { SITE_ROOT }
+    fn channel(&self) -> ChannelMeta { CHANNEL }
+    fn list_base(&self) -> &'static str { LIST_BASE }
+    fn list_url(&self, page: usize) -> String { list_url(page) }
+    fn parse_list(&self, body: &str, url: &str, stats: &mut ListStats) -> Result<Vec<Notice>, ScrapeError> {
+        parse_list(body, url, stats)
+    }
+    // 상세 페이지(boardview.do) 본문
+    fn detail_classes(&self) -> &'static [&'static str] { &["view_contents", "board_view_content", "view-content", "board_view"] }
 }
 
 /* ─── 유틸 ─── */
 
-fn parse_board_move(onclick: &str) -> Option<(String, String)> {
+/// onclick="boardMove('경로','pkid')" → (경로, pkid)
+pub fn parse_board_move(onclick: &str) -> Option<(String, String)> {
     // 예: onclick="boardMove('/front/boardview.do','506633');"
     let lp = onclick.find('(')? + 1;
     let rp = onclick[lp..].find(')')? + lp;
@@ -1,64 +1,52 @@
 // src/schools/sookmyung.rs
-use reqwest::blocking::Client;
-use reqwest::header::REFERER;
+use async_trait::async_trait;
 use select::document::Document;
 use select::predicate::{Name, Class, Predicate};
-use std::error::Error;
-use std::time::Duration;
 
-pub struct Notice {
-    pub title: String,
-    pub date: String,
-    pub url: String,
-}
-
-pub fn fetch_notices() -> Result<Vec<Notice>, Box<dyn Error>> {
-    let client = Client::builder()
-        .user_agent("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/114.0.0.0 Safari/537.36")
-        .timeout(Duration::from_secs(10))
-        .build()?;
+use crate::error::{check_parsed, ScrapeError};
+use crate::health::ListStats;
+use crate::model::Notice;
+use crate::schools::{is_pinned_row, query_param, ChannelMeta, School};
 
-    let base_url = "https://www.sookmyung.ac.kr/kr/news/important-notice.do";
-    let article_limit = 10;
-    let offset = 0;
+const SCHOOL: &str = "sookmyung";
+const SITE_ROOT: &str = "https://www.sookmyung.ac.kr";
 
-    let url = format!(
-        "{}?mode=list&articleLimit={}&article.offset={}",
-        base_url, article_limit, offset
-    );
-
-    let res = match client.get(&url).header(REFERER, base_url).send() {
-        Ok(r) if r.status().is_success() => r,
-        Ok(r) => {
-            eprintln!("Failed to fetch page: HTTP {}", r.status());
-            return Ok(Vec::new());
-        }
-        Err(e) => {
-            eprintln!("Network request failed: {}", e);
-            return Ok(Vec::new());
-        }
-    };
+const BASE_URL: &str = "https://www.sookmyung.ac.kr/kr/news/important-notice.do";
 
-    let body = match res.text() {
-        Ok(text) => text,
-        Err(e) => {
-            eprintln!("Failed to read response text: {}", e);
-            return Ok(Vec::new());
-        }
-    };
+/// 목록 페이지 주소 (article.offset으로 페이지 이동)
+fn list_url(page: usize) -> String {
+    let article_limit = 10;
+    let offset = (page - 1) * article_limit;
+    format!("{BASE_URL}?mode=list&articleLimit={article_limit}&article.offset={offset}")
+}
 
-    let document = Document::from(body.as_str());
+/// 목록 페이지 HTML → 공지 목록 (행/누락 수와 구조 지문은 `stats`에 누적)
+pub fn parse_list(body: &str, url: &str, stats: &mut ListStats) -> Result<Vec<Notice>, ScrapeError> {
+    let document = Document::from(body);
+    stats.begin_page(document.find(Name("table").descendant(Name("tbody"))).next());
     let mut notices = Vec::new();
+    let mut rows = 0;
+    let mut first_failure = None;
 
-    for tr in document.find(Name("table").descendant(Name("tbody")).descendant(Name("tr"))) {
-        let title = tr.find(Class("b-td-title"))
+    for (i, tr) in document.find(Name("table").descendant(Name("tbody")).descendant(Name("tr"))).enumerate() {
+        rows += 1;
+        stats.rows += 1;
+        let a = tr.find(Class("b-td-title"))
             .next()
-            .and_then(|td| td.find(Name("a")).next())
-            .map(|a| a.text().trim().to_string())
-            .unwrap_or_else(|| {
-                eprintln!("Warning: Failed to parse title");
-                "(제목 없음)".to_string()
-            });
+            .and_then(|td| td.find(Name("a")).next());
+
+        let title = a.as_ref().map(|a| a.text().trim().to_string()).unwrap_or_default();
+        let href = a.as_ref().and_then(|a| a.attr("href")).unwrap_or_default();
+        if title.is_empty() || href.is_empty() {
+            eprintln!("Warning: Failed to parse title/link (row {})", i + 1);
+            if title.is_empty() {
+                stats.missing_title += 1;
+            } else {
+                stats.missing_url += 1;
+            }
+            first_failure.get_or_insert((i + 1, "b-td-title 안에 제목 링크 없음".to_string()));
+            continue;
+        }
 
         let date = tr.find(Class("b-date-box").or(Class("b-date")))
             .next()
@@ -68,37 +56,49 @@ pub fn fetch_notices() -> Result<Vec<Notice>, Box<dyn Error>> {
                 "N/A".to_string()
             });
 
-        let url = tr.find(Class("b-td-title"))
-            .next()
-            .and_then(|td| td.find(Name("a")).next())
-            .and_then(|a| a.attr("href"))
-            .map(|s| format!("https://www.sookmyung.ac.kr/kr/news/important-notice.do{}", s))
-            .unwrap_or_default();
+        let url = format!("{}{}", BASE_URL, href);
 
-        notices.push(Notice { title, date, url });
+        let mut notice = Notice::new(SCHOOL, title, date, url);
+        // 글 번호: href="?mode=view&articleNo=123456&..."
+        notice.id = query_param(href, "articleNo");
+        // 상단 고정: tr.b-top-box 또는 번호 칸의 span.b-notice "공지" 배지
+        notice.pinned = is_pinned_row(&tr, &["b-top-box", "b-notice"]);
+        notice.author = tr.find(Class("b-writer"))
+            .next()
+            .map(|n| n.text().trim().to_string())
+            .filter(|s| !s.is_empty());
+        notice.views = tr.find(Class("b-hit"))
+            .next()
+            .and_then(|n| n.text().trim().replace(',', "").parse().ok());
+        notices.push(notice);
     }
 
-    Ok(notices)
+    check_parsed(SCHOOL, url, rows, first_failure, notices)
 }
 
-// RSS 생성 함수
-use rss::{ChannelBuilder, ItemBuilder};
-
-pub fn create_rss(notices: &[Notice]) -> rss::Channel {
-    let items = notices.iter().map(|notice| {
-        ItemBuilder::default()
-            .title(notice.title.clone())
-            .link(notice.url.clone())
-            .pub_date(notice.date.clone())
-            .build()  
-    }).collect::<Vec<_>>();
-
-    let channel = ChannelBuilder::default()
-        .title("숙명여자대학교 공지 RSS")
-        .link("https://www.sookmyung.ac.kr/kr/news/important-notice.do")
-        .description("숙명여대 주요 공지 RSS 피드")
-        .items(items)
-        .build(); 
-
-    channel
+// RSS 채널 정보
+pub const CHANNEL: ChannelMeta = ChannelMeta {
+    title: "숙명여자대학교 공지 RSS",
+    link: "https://www.sookmyung.ac.kr/kr/news/important-notice.do",
+    description: "숙명여대 주요 공지 RSS 피드",
+};
+
+/* ─── 레지스트리 등록 ─── */
+
+pub struct Sookmyung;
+
+#[async_trait]
+impl School for Sookmyung {
+    fn key(&self) -> &'static str { SCHOOL }
+    fn aliases(&self) -> &'static [&'static str] { &["sm", "숙명"] }
+    fn display_name(&self) -> &'static str { "숙명여자대학교" }
+    fn base_url(&self) -> &'static str { SITE_ROOT }
+    fn channel(&self) -> ChannelMeta { CHANNEL }
+    fn list_base(&self) -> &'static str { BASE_URL }
+    fn list_url(&self, page: usize) -> String { list_url(page) }
+    fn parse_list(&self, body: &str, url: &str, stats: &mut ListStats) -> Result<Vec<Notice>, ScrapeError> {
+        parse_list(body, url, stats)
+    }
+    // 상세 페이지(mode=view) 본문
+    fn detail_classes(&self) -> &'static [&'static str] { &["b-content-box", "b-con-box", "fr-view"] }
 }
@@ -0,0 +1,250 @@
+// src/search.rs
+// 저장된 공지 검색: 한국어용 토큰화와 검색 조건
+//
+// 한글은 띄어쓰기를 무시하고 2글자씩(바이그램) 자른 토큰으로 색인합니다. 그래서 "수강신청"과 "수강 신청"이
+// 같은 토큰(수강·강신·신청)이 되고, "장학금을"처럼 조사가 붙은 글도 "장학금"(장학·학금)으로 찾힙니다.
+// 검색어 쪽은 끝의 조사를 떼어 "장학금은"으로 찾아도 맞도록 합니다. 실제 검색은 저장소의 FTS5 색인에서 합니다.
+
+use chrono::{DateTime, FixedOffset, NaiveDate, TimeZone};
+use serde::Serialize;
+
+use crate::model::{self, kst, Notice};
+use crate::schools::escape_html;
+use crate::store::StoredNotice;
+
+/// 검색어 끝에서 떼어 낼 조사 (긴 것부터)
+const PARTICLES: &[&str] = &[
+    "에서는", "으로는", "이라는", "에게서", "까지", "부터", "에서", "으로", "에게", "한테", "이나", "이랑", "처럼", "보다",
+    "은", "는", "이", "가", "을", "를", "의", "에", "로", "와", "과", "도", "만", "나", "랑",
+];
+
+fn is_hangul(c: char) -> bool {
+    matches!(c, '\u{AC00}'..='\u{D7A3}' | '\u{1100}'..='\u{11FF}' | '\u{3130}'..='\u{318F}')
+}
+
+/// 색인/검색 공통 토큰화: 한글 구간은 공백을 빼고 바이그램(1글자면 그대로), 영문/숫자는 소문자 단어
+pub fn tokenize(text: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut hangul: Vec<char> = Vec::new();
+    let mut word = String::new();
+    let flush_hangul = |hangul: &mut Vec<char>, tokens: &mut Vec<String>| {
+        match hangul.len() {
+            0 => {}
+            1 => tokens.push(hangul[0].to_string()),
+            _ => tokens.extend(hangul.windows(2).map(|w| w.iter().collect::<String>())),
+        }
+        hangul.clear();
+    };
+    for c in text.chars() {
+        if is_hangul(c) {
+            if !word.is_empty() {
+                tokens.push(std::mem::take(&mut word));
+            }
+            hangul.push(c);
+        } else if c.is_alphanumeric() {
+            flush_hangul(&mut hangul, &mut tokens);
+            word.extend(c.to_lowercase());
+        } else {
+            if !word.is_empty() {
+                tokens.push(std::mem::take(&mut word));
+            }
+            // 한 줄 안에서 한글 사이의 공백/가운뎃점은 이어 붙임 ("수강 신청" = "수강신청", "휴·복학" = "휴복학")
+            if !(c.is_whitespace() || c == '·') || c == '\n' {
+                flush_hangul(&mut hangul, &mut tokens);
+            }
+        }
+    }
+    flush_hangul(&mut hangul, &mut tokens);
+    if !word.is_empty() {
+        tokens.push(word);
+    }
+    tokens
+}
+
+/// 한글 단어 끝의 조사 떼기. 남는 부분이 2글자 미만이면 그대로
+pub fn strip_particle(word: &str) -> &str {
+    for p in PARTICLES {
+        if let Some(stem) = word.strip_suffix(p) {
+            if stem.chars().count() >= 2 && stem.chars().all(is_hangul) {
+                return stem;
+            }
+        }
+    }
+    word
+}
+
+/// 색인할 글 내용: 제목 + 분류 + 본문(없으면 요약) + 첨부 이름
+pub fn index_text(n: &Notice) -> String {
+    let body = match (&n.body_html, &n.summary) {
+        (Some(html), _) => model::html_text(html),
+        (None, Some(summary)) => summary.clone(),
+        (None, None) => String::new(),
+    };
+    let attachments: Vec<&str> = n.attachments.iter().map(|a| a.name.as_str()).collect();
+    format!(
+        "{}\n{}\n{}\n{}",
+        n.title,
+        n.category.as_deref().unwrap_or_default(),
+        body,
+        attachments.join("\n")
+    )
+}
+
+/// FTS5 MATCH 식: 모든 토큰을 포함 (한 글자 한글은 그 글자로 시작하는 토큰).
+/// 검색어는 단어마다 따로 토큰화하므로 "수강 신청"은 본문의 "수강신청"과도, "수강 신청 (변경)"과도 맞음
+pub fn match_expr(query: &str) -> Option<String> {
+    let terms: Vec<String> = query
+        .split_whitespace()
+        .flat_map(|word| tokenize(strip_particle(word)))
+        .map(|t| {
+            if t.chars().count() == 1 && t.chars().all(is_hangul) {
+                format!("\"{t}\"*")
+            } else {
+                format!("\"{t}\"")
+            }
+        })
+        .collect();
+    (!terms.is_empty()).then(|| terms.join(" AND "))
+}
+
+/// 검색 조건 (CLI `search`, HTTP `/api/search`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchQuery {
+    pub text: String,
+    /// 학교 키 (없으면 전체)
+    pub school: Option<&'static str>,
+    /// 게시일 범위 (양 끝 포함, 게시일을 모르면 처음 본 날)
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+    pub limit: usize,
+}
+
+impl SearchQuery {
+    pub fn new(text: &str) -> Self {
+        SearchQuery {
+            text: text.trim().to_string(),
+            school: None,
+            from: None,
+            to: None,
+            limit: 20,
+        }
+    }
+
+    /// `--school dongduk`처럼 키나 별칭으로 학교 지정
+    pub fn school(mut self, name: Option<&str>) -> Result<Self, String> {
+        self.school = match name.filter(|n| !n.is_empty()) {
+            None => None,
+            Some(n) => Some(crate::schools::find(n).ok_or_else(|| format!("학교 '{n}'는 없습니다."))?.key()),
+        };
+        Ok(self)
+    }
+
+    /// "2025-02-01" 형식
+    pub fn dates(mut self, from: Option<&str>, to: Option<&str>) -> Result<Self, String> {
+        let parse = |s: &str| {
+            NaiveDate::parse_from_str(s.trim(), "%Y-%m-%d").map_err(|_| format!("날짜는 YYYY-MM-DD 형식이어야 합니다: {s}"))
+        };
+        self.from = from.filter(|s| !s.is_empty()).map(parse).transpose()?;
+        self.to = to.filter(|s| !s.is_empty()).map(parse).transpose()?;
+        Ok(self)
+    }
+
+    /// CLI 인자: `장학금 --school dongduk --from 2025-01-01 --to 2025-02-28 --limit 10`
+    pub fn from_args(args: &[String]) -> Result<Self, String> {
+        let (mut words, mut school, mut from, mut to, mut limit) = (Vec::new(), None, None, None, None);
+        let mut it = args.iter();
+        while let Some(arg) = it.next() {
+            let slot = match arg.as_str() {
+                "--school" => &mut school,
+                "--from" => &mut from,
+                "--to" => &mut to,
+                "--limit" => &mut limit,
+                flag if flag.starts_with("--") => return Err(format!("알 수 없는 옵션: {flag}")),
+                word => {
+                    words.push(word);
+                    continue;
+                }
+            };
+            *slot = Some(it.next().ok_or_else(|| format!("{arg} 뒤에 값이 필요합니다."))?.as_str());
+        }
+        let mut query = SearchQuery::new(&words.join(" ")).school(school)?.dates(from, to)?;
+        if let Some(n) = limit {
+            query.limit = n.parse().map_err(|_| format!("--limit은 숫자여야 합니다: {n}"))?;
+        }
+        Ok(query)
+    }
+
+    /// 범위 시작 (KST 0시)
+    pub fn from_time(&self) -> Option<DateTime<FixedOffset>> {
+        self.from.and_then(midnight)
+    }
+
+    /// 범위 끝 (`to` 다음 날 KST 0시, 이 시각은 포함 안 함)
+    pub fn until_time(&self) -> Option<DateTime<FixedOffset>> {
+        self.to.and_then(|d| d.succ_opt()).and_then(midnight)
+    }
+}
+
+fn midnight(d: NaiveDate) -> Option<DateTime<FixedOffset>> {
+    kst().from_local_datetime(&d.and_hms_opt(0, 0, 0)?).single()
+}
+
+/// 검색 결과 1건 (HTTP 응답용)
+#[derive(Debug, Clone, Serialize)]
+pub struct SearchResult {
+    pub guid: String,
+    pub school: String,
+    pub title: String,
+    pub url: String,
+    pub category: Option<String>,
+    /// RFC 3339 게시일 (모르면 처음 본 시각)
+    pub published: String,
+    pub summary: Option<String>,
+    pub attachments: Vec<String>,
+    /// 게시판에서 사라진 글이면 true
+    pub removed: bool,
+}
+
+impl From<&StoredNotice> for SearchResult {
+    fn from(s: &StoredNotice) -> Self {
+        let n = &s.notice;
+        SearchResult {
+            guid: n.guid(),
+            school: n.school.clone(),
+            title: n.title.clone(),
+            url: n.url.clone(),
+            category: n.category.clone(),
+            published: n.pub_date().to_rfc3339(),
+            summary: n.summary.clone(),
+            attachments: n.attachments.iter().map(|a| a.name.clone()).collect(),
+            removed: s.removed_at.is_some(),
+        }
+    }
+}
+
+/// 브라우저용 검색 결과 페이지 (OpenSearch `text/html` 결과)
+pub fn results_html(query: &str, results: &[SearchResult]) -> String {
+    let query = escape_html(query);
+    let mut html = format!(
+        "<!DOCTYPE html>\n<html lang=\"ko\">\n<head><meta charset=\"utf-8\"><title>{query} - 학사 공지 검색</title></head>\n<body>\n\
+         <form action=\"/search\"><input name=\"q\" value=\"{query}\"> <button>검색</button></form>\n\
+         <p>{}건</p>\n<ol>\n",
+        results.len()
+    );
+    for r in results {
+        let removed = if r.removed { " (삭제됨)" } else { "" };
+        html.push_str(&format!(
+            "<li><a href=\"{}\">{}</a>{removed} — {} · {}",
+            escape_html(&r.url),
+            escape_html(&r.title),
+            escape_html(&r.school),
+            r.published.get(..10).unwrap_or(&r.published)
+        ));
+        if let Some(summary) = &r.summary {
+            html.push_str(&format!("<br>{}", escape_html(summary)));
+        }
+        html.push_str("</li>\n");
+    }
+    html.push_str("</ol>\n</body>\n</html>\n");
+    html
+}
@@ -0,0 +1,86 @@
+// src/singleflight.rs
+// 학교별 단일 수집(single-flight): 같은 학교를 동시에 여러 요청이 원하면 한 번만 긁고 결과를 나눠 씀
+//
+// 먼저 온 요청이 수집하고(리더), 그동안 온 요청은 그 결과를 기다립니다(합류). 리더 요청이 중간에
+// 끊기면(클라이언트 연결 종료 등) 기다리던 요청 중 하나가 이어서 수집합니다.
+
+use std::collections::{BTreeMap, HashMap};
+use std::future::Future;
+use std::sync::Mutex;
+use tokio::sync::watch;
+
+pub struct SingleFlight<T> {
+    inflight: Mutex<HashMap<&'static str, watch::Receiver<Option<T>>>>,
+    /// 학교별로 다른 요청의 수집에 합류한 횟수
+    coalesced: Mutex<BTreeMap<&'static str, u64>>,
+}
+
+impl<T> Default for SingleFlight<T> {
+    fn default() -> Self {
+        SingleFlight {
+            inflight: Mutex::new(HashMap::new()),
+            coalesced: Mutex::new(BTreeMap::new()),
+        }
+    }
+}
+
+impl<T: Clone> SingleFlight<T> {
+    pub fn new() -> Self {
+        SingleFlight::default()
+    }
+
+    /// `key`의 수집이 진행 중이면 그 결과를 기다리고, 아니면 `work`를 실행해 결과를 나눠 줌
+    pub async fn run<F, Fut>(&self, key: &'static str, work: F) -> T
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = T>,
+    {
+        let mut work = Some(work);
+        loop {
+            let joined = {
+                let mut inflight = self.inflight.lock().unwrap();
+                match inflight.get(key) {
+                    Some(rx) => Ok(rx.clone()),
+                    None => {
+                        let (tx, rx) = watch::channel(None);
+                        inflight.insert(key, rx);
+                        Err(tx)
+                    }
+                }
+            };
+            match joined {
+                Err(tx) => {
+                    let _flight = Flight { inflight: &self.inflight, key };
+                    let work = work.take().expect("리더는 한 번만 됨");
+                    let value = work().await;
+                    tx.send_replace(Some(value.clone()));
+                    return value;
+                }
+                Ok(mut rx) => {
+                    if let Ok(value) = rx.wait_for(Option::is_some).await {
+                        *self.coalesced.lock().unwrap().entry(key).or_default() += 1;
+                        return value.clone().expect("Some만 기다림");
+                    }
+                    // 리더가 결과 없이 끊김 → 다시 시도 (이번에는 리더가 될 수 있음)
+                }
+            }
+        }
+    }
+
+    /// 학교별 합류 횟수
+    pub fn coalesced(&self) -> BTreeMap<&'static str, u64> {
+        self.coalesced.lock().unwrap().clone()
+    }
+}
+
+/// 리더의 수집이 끝나거나 취소되면 진행 중 표시를 지움
+struct Flight<'a, T> {
+    inflight: &'a Mutex<HashMap<&'static str, watch::Receiver<Option<T>>>>,
+    key: &'static str,
+}
+
+impl<T> Drop for Flight<'_, T> {
+    fn drop(&mut self) {
+        self.inflight.lock().unwrap().remove(self.key);
+    }
+}
@@ -1,6 +1,12 @@
+use std::collections::HashSet;
 use std::fs;
+use std::io::BufReader;
 use std::path::Path;
 
+use chrono::DateTime;
+
+use crate::model::{kst, Attachment, Notice};
+
 pub fn save_rss_xml(channel: &rss::Channel, path: &str) -> std::io::Result<()> {
     let xml = channel.to_string();
     if let Some(parent) = Path::new(path).parent() {
@@ -9,15 +15,112 @@ pub fn save_rss_xml(channel: &rss::Channel, path: &str) -> std::io::Result<()> {
     fs::write(path, xml)
 }
 
-// notices를 마크다운 리스트로 저장
-pub fn save_markdown(notices: &[crate::schools::sookmyung::Notice], path: &str, title: &str) -> std::io::Result<()> {
-    if let Some(parent) = Path::new(path).parent() {
-        fs::create_dir_all(parent)?;
-    }
+// notices를 마크다운 리스트로 (index.md)
+pub fn render_markdown(notices: &[Notice], title: &str) -> String {
     let mut md = String::new();
     md.push_str(&format!("# {title}\n\n"));
     for n in notices {
-        md.push_str(&format!("- [{}]({}) — `{}`\n", n.title, n.url, n.date));
+        md.push_str(&format!("- [{}]({}) — `{}`\n", n.title, n.url, n.pub_date_rfc2822()));
+        for a in &n.attachments {
+            let size = a.size_label().map(|s| format!(" ({s})")).unwrap_or_default();
+            md.push_str(&format!("  - 첨부: [{}]({}){}\n", a.name, a.url, size));
+        }
+    }
+    md
+}
+
+// 여러 파일을 한꺼번에 교체: 모두 `<경로>.tmp`에 쓴 뒤에야 rename으로 바꿔 끼움.
+// 쓰다가 하나라도 실패하면 임시 파일을 지우고 기존 파일은 그대로 둠 (새 rss.xml + 옛 atom.xml 같은 섞임 방지)
+pub fn write_files_atomically(files: &[(String, String)]) -> std::io::Result<()> {
+    let tmp_path = |path: &str| format!("{path}.tmp");
+    let staged = files.iter().try_for_each(|(path, contents)| {
+        if let Some(parent) = Path::new(path).parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(tmp_path(path), contents)
+    });
+    if let Err(e) = staged {
+        for (path, _) in files {
+            let _ = fs::remove_file(tmp_path(path));
+        }
+        return Err(e);
+    }
+    files.iter().try_for_each(|(path, _)| fs::rename(tmp_path(path), path))
+}
+
+// 이전에 만든 rss.xml (없거나 읽을 수 없으면 None)
+fn read_feed(path: &str) -> Option<rss::Channel> {
+    let file = fs::File::open(path).ok()?;
+    match rss::Channel::read_from(BufReader::new(file)) {
+        Ok(channel) => Some(channel),
+        Err(e) => {
+            eprintln!("이전 피드 읽기 실패 ({path}): {e}");
+            None
+        }
     }
-    fs::write(path, md)
-}
\ No newline at end of file
+}
+
+// 이전에 만든 rss.xml의 GUID/링크 목록 (없거나 읽을 수 없으면 빈 집합)
+pub fn load_feed_seen(path: &str) -> HashSet<String> {
+    let Some(channel) = read_feed(path) else {
+        return HashSet::new();
+    };
+    channel
+        .items()
+        .iter()
+        .flat_map(|i| [i.guid().map(|g| g.value()), i.link()])
+        .flatten()
+        .map(str::to_string)
+        .collect()
+}
+
+// 이전에 만든 rss.xml의 항목을 공지로 되살림 (build_rss의 역방향, 저장소가 비었을 때 이어 쓰기용).
+// 설명에 붙였던 수정 표시/첨부 목록은 떼어 내고, 첨부는 enclosure 1개만 복원됨
+pub fn load_feed_notices(path: &str, school: &str) -> Vec<Notice> {
+    let Some(channel) = read_feed(path) else {
+        return Vec::new();
+    };
+    let prefix = format!("{school}:");
+    channel
+        .items()
+        .iter()
+        .filter_map(|item| {
+            let (title, link) = (item.title()?, item.link()?);
+            let date_raw = item.pub_date().unwrap_or_default().to_string();
+            let mut n = Notice::new(school, title.to_string(), date_raw, link.to_string());
+            n.id = item
+                .guid()
+                .and_then(|g| g.value().strip_prefix(&prefix))
+                .map(str::to_string);
+            // 다른 도구가 고친 피드면 오프셋이 다를 수 있어 KST로 맞춤
+            n.published = DateTime::parse_from_rfc2822(&n.date_raw).ok().map(|d| d.with_timezone(&kst()));
+            n.category = item.categories().first().map(|c| c.name().to_string());
+            n.author = item
+                .dublin_core_ext()
+                .and_then(|dc| dc.creators().first())
+                .cloned();
+            n.body_html = item.content().map(str::to_string);
+            n.summary = item.description().map(feed_summary).filter(|s| !s.is_empty());
+            n.attachments = item
+                .enclosure()
+                .map(|e| {
+                    let name = e.url().rsplit('/').next().unwrap_or(e.url()).to_string();
+                    let mut a = Attachment::new(name, e.url().to_string(), e.length().parse().ok().filter(|&n| n > 0));
+                    a.mime = Some(e.mime_type().to_string());
+                    a
+                })
+                .into_iter()
+                .collect();
+            Some(n)
+        })
+        .collect()
+}
+
+// 피드 설명 → 원래 요약 (앞의 "수정됨 (...)" 줄과 뒤의 첨부 목록 HTML 제거)
+fn feed_summary(description: &str) -> String {
+    let s = match description.strip_prefix("수정됨 (") {
+        Some(rest) => rest.split_once("\n\n").map_or("", |(_, summary)| summary),
+        None => description,
+    };
+    s.split("<p>첨부파일</p>").next().unwrap_or_default().trim().to_string()
+}
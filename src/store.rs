@@ -0,0 +1,520 @@
+// src/store.rs
+// 공지 저장소 (SQLite): 실행마다 수집한 공지를 쌓아 두고, 피드/마크다운은 저장소에서 만듭니다.
+//
+// 글마다 처음 본 시각(first_seen), 마지막으로 본 시각(last_seen), 내용이 마지막으로 바뀐 시각(last_changed)을
+// 남깁니다. 게시일을 읽지 못한 글은 first_seen을 게시일로 씁니다. 게시판에서 사라진 글은 지우지 않고
+// removed_at을 남겨 피드에서만 뺍니다. 내용이 바뀔 때마다 그 시점의 제목/게시일/본문/첨부를 revisions에 쌓습니다.
+
+use chrono::{DateTime, Days, FixedOffset, NaiveTime, TimeZone};
+use rusqlite::{params, Connection, OptionalExtension, Row};
+use std::collections::HashSet;
+use std::path::Path;
+use std::sync::Mutex;
+
+use crate::diff::{self, Change, CrawlDiff, Edit, Revision, Tombstone};
+use crate::model::{kst, Attachment, Notice, PinnedMode};
+use crate::search::{self, SearchQuery};
+
+/// 스키마 변경 이력. i번째 항목을 적용하면 `PRAGMA user_version`이 i+1이 됨 (이미 배포된 항목은 고치지 말고 뒤에 추가)
+const MIGRATIONS: &[&str] = &[
+    // 1: 공지 테이블
+    "CREATE TABLE notices (
+        guid          TEXT PRIMARY KEY,
+        school        TEXT NOT NULL,
+        board_id      TEXT,
+        title         TEXT NOT NULL,
+        category      TEXT,
+        author        TEXT,
+        views         INTEGER,
+        pinned        INTEGER NOT NULL DEFAULT 0,
+        date_raw      TEXT NOT NULL,
+        published     TEXT,
+        url           TEXT NOT NULL,
+        body_html     TEXT,
+        summary       TEXT,
+        attachments   TEXT NOT NULL DEFAULT '[]',
+        first_seen    TEXT NOT NULL,
+        last_seen     TEXT NOT NULL,
+        last_changed  TEXT NOT NULL
+    );
+    CREATE INDEX notices_school_published ON notices (school, published);",
+    // 2: 게시판에서 사라진 글 표시
+    "ALTER TABLE notices ADD COLUMN removed_at TEXT;",
+    // 3: 글마다 수정 이력 (처음 본 내용 + 바뀔 때마다 1행)
+    "CREATE TABLE revisions (
+        guid          TEXT NOT NULL REFERENCES notices (guid),
+        seq           INTEGER NOT NULL,
+        seen_at       TEXT NOT NULL,
+        title         TEXT NOT NULL,
+        published     TEXT,
+        body_hash     TEXT,
+        body_text     TEXT,
+        attachments   TEXT NOT NULL DEFAULT '[]',
+        PRIMARY KEY (guid, seq)
+    );",
+    // 4: 검색 색인. tokens는 search::tokenize 결과를 공백으로 이은 것 (내용은 init에서 채움)
+    "CREATE VIRTUAL TABLE notice_search USING fts5 (notice_guid UNINDEXED, tokens);",
+    // 5: 시각을 모두 KST(+09:00)로. 시각은 TEXT로 문자열 비교하므로 다른 오프셋(가져온 피드의 +00:00 등)이
+    //    섞이면 순서가 틀어짐. SQLite 날짜 함수는 오프셋을 UTC로 바꿔 읽으므로 9시간을 더해 다시 씀
+    "UPDATE notices SET published = strftime('%Y-%m-%d %H:%M:%S', published, '+9 hours') || '+09:00'
+     WHERE published IS NOT NULL AND published NOT LIKE '%+09:00' AND julianday(published) IS NOT NULL;
+    UPDATE notices SET first_seen = strftime('%Y-%m-%d %H:%M:%S', first_seen, '+9 hours') || '+09:00'
+     WHERE first_seen IS NOT NULL AND first_seen NOT LIKE '%+09:00' AND julianday(first_seen) IS NOT NULL;
+    UPDATE notices SET last_seen = strftime('%Y-%m-%d %H:%M:%S', last_seen, '+9 hours') || '+09:00'
+     WHERE last_seen IS NOT NULL AND last_seen NOT LIKE '%+09:00' AND julianday(last_seen) IS NOT NULL;
+    UPDATE notices SET last_changed = strftime('%Y-%m-%d %H:%M:%S', last_changed, '+9 hours') || '+09:00'
+     WHERE last_changed IS NOT NULL AND last_changed NOT LIKE '%+09:00' AND julianday(last_changed) IS NOT NULL;
+    UPDATE notices SET removed_at = strftime('%Y-%m-%d %H:%M:%S', removed_at, '+9 hours') || '+09:00'
+     WHERE removed_at IS NOT NULL AND removed_at NOT LIKE '%+09:00' AND julianday(removed_at) IS NOT NULL;
+    UPDATE revisions SET seen_at = strftime('%Y-%m-%d %H:%M:%S', seen_at, '+9 hours') || '+09:00'
+     WHERE seen_at IS NOT NULL AND seen_at NOT LIKE '%+09:00' AND julianday(seen_at) IS NOT NULL;
+    UPDATE revisions SET published = strftime('%Y-%m-%d %H:%M:%S', published, '+9 hours') || '+09:00'
+     WHERE published IS NOT NULL AND published NOT LIKE '%+09:00' AND julianday(published) IS NOT NULL;",
+];
+
+/// 검색 색인이 생긴 스키마 버전. 이보다 낮은 저장소를 열면 색인을 새로 만듦
+const SEARCH_INDEX_VERSION: usize = 4;
+
+const COLUMNS: &str = "school, board_id, title, category, author, views, pinned, date_raw, published, url, \
+                       body_html, summary, attachments, first_seen, last_seen, last_changed, removed_at";
+
+/// 저장소에서 읽은 공지 (`notice.first_seen`은 항상 Some)
+#[derive(Debug, Clone)]
+pub struct StoredNotice {
+    pub notice: Notice,
+    pub last_seen: DateTime<FixedOffset>,
+    pub last_changed: DateTime<FixedOffset>,
+    /// 게시판에서 사라진 시각 (사라지지 않았으면 None)
+    pub removed_at: Option<DateTime<FixedOffset>>,
+}
+
+pub struct Store {
+    conn: Mutex<Connection>,
+}
+
+impl Store {
+    /// 파일을 열고(없으면 만듦) 스키마를 최신으로 올림
+    pub fn open(path: impl AsRef<Path>) -> rusqlite::Result<Self> {
+        if let Some(parent) = path.as_ref().parent() {
+            // 디렉터리를 못 만들면 아래 open이 오류를 냄
+            let _ = std::fs::create_dir_all(parent);
+        }
+        Store::init(Connection::open(path)?)
+    }
+
+    /// 테스트용 메모리 저장소
+    pub fn open_in_memory() -> rusqlite::Result<Self> {
+        Store::init(Connection::open_in_memory()?)
+    }
+
+    fn init(mut conn: Connection) -> rusqlite::Result<Self> {
+        let from = migrate(&mut conn)?;
+        if from < SEARCH_INDEX_VERSION {
+            reindex(&mut conn)?;
+        }
+        Ok(Store { conn: Mutex::new(conn) })
+    }
+
+    /// 현재 스키마 버전 (`PRAGMA user_version`)
+    pub fn schema_version(&self) -> rusqlite::Result<usize> {
+        user_version(&self.conn.lock().unwrap())
+    }
+
+    /// 한 학교의 목록 수집 결과 반영. 새 글은 추가하고, 있던 글은 last_seen을 갱신하며
+    /// 제목/게시일/본문/첨부가 바뀌었으면 last_changed도 갱신. 읽은 범위에 있어야 할 글이 없으면 삭제 표시
+    pub fn upsert(&self, school: &str, notices: &[Notice], now: DateTime<FixedOffset>) -> rusqlite::Result<CrawlDiff> {
+        let now = kst_time(now);
+        let mut conn = self.conn.lock().unwrap();
+        let tx = conn.transaction()?;
+        let mut diff = CrawlDiff::new(school);
+        for n in notices {
+            let guid = n.guid();
+            let old = tx
+                .query_row(&format!("SELECT {COLUMNS} FROM notices WHERE guid = ?1"), [&guid], stored_from_row)
+                .optional()?;
+            let Some(old) = old else {
+                diff.new.push(n.clone());
+                write_notice(&tx, &guid, n, now, now, now)?;
+                add_revision(&tx, &guid, n, now)?;
+                continue;
+            };
+            let merged = merge(&old.notice, n);
+            let first_seen = old.notice.first_seen.unwrap_or(now);
+            let changes = diff::changes(&old.notice, &merged);
+            let last_changed = if changes.is_empty() { old.last_changed } else { now };
+            if !changes.is_empty() {
+                // 이력 기록 전부터 있던 글은 바뀌기 전 내용을 첫 버전으로 남김
+                if revision_count(&tx, &guid)? == 0 {
+                    add_revision(&tx, &guid, &old.notice, old.last_changed)?;
+                }
+                add_revision(&tx, &guid, &merged, now)?;
+            }
+            if old.removed_at.is_some() {
+                diff.new.push(merged.clone());
+            } else if changes.is_empty() {
+                diff.unchanged.push(guid.clone());
+            } else {
+                diff.edited.push(Edit {
+                    notice: merged.clone(),
+                    changes,
+                });
+            }
+            write_notice(&tx, &guid, &merged, first_seen, now, last_changed)?;
+        }
+        // 상단 고정 글은 매번 1페이지에 나오므로, 이번에 못 본 글은 더 이상 고정이 아님
+        tx.execute(
+            "UPDATE notices SET pinned = 0 WHERE school = ?1 AND pinned = 1 AND last_seen < ?2",
+            params![school, now],
+        )?;
+        if let Some(since) = window_start(notices) {
+            diff.removed = mark_removed(&tx, school, since, now)?;
+        }
+        tx.commit()?;
+        Ok(diff)
+    }
+
+    /// 피드에 실을 최근 공지: 상단 고정 글 먼저, 그다음 게시일(모르면 처음 본 시각) 최신순
+    pub fn recent(&self, school: &str, pinned: PinnedMode, limit: usize) -> rusqlite::Result<Vec<StoredNotice>> {
+        self.recent_since(school, pinned, limit, None)
+    }
+
+    /// `recent`와 같되 게시일도, 게시판에서 마지막으로 본 시각도 `since`보다 오래된 글은 뺌
+    pub fn recent_since(
+        &self,
+        school: &str,
+        pinned: PinnedMode,
+        limit: usize,
+        since: Option<DateTime<FixedOffset>>,
+    ) -> rusqlite::Result<Vec<StoredNotice>> {
+        let pinned_filter: Option<bool> = match pinned {
+            PinnedMode::Include => None,
+            PinnedMode::Exclude => Some(false),
+            PinnedMode::Only => Some(true),
+        };
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare_cached(&format!(
+            "SELECT {COLUMNS} FROM notices
+             WHERE school = ?1 AND removed_at IS NULL AND (?2 IS NULL OR pinned = ?2)
+               AND (?4 IS NULL OR last_seen >= ?4 OR COALESCE(published, first_seen) >= ?4)
+             ORDER BY pinned DESC, COALESCE(published, first_seen) DESC, first_seen DESC
+             LIMIT ?3"
+        ))?;
+        let since = since.map(kst_time);
+        let rows = stmt.query_map(params![school, pinned_filter, limit as i64, since], stored_from_row)?;
+        rows.collect()
+    }
+
+    /// 다른 곳(이전에 배포한 피드 등)에 남아 있던 글 가져오기. 저장소에 이미 있는 글(삭제 표시된 글 포함)은
+    /// 건드리지 않음. 처음 본 시각은 `first_seen`, 없으면 게시일로 추정. 가져온 건수를 돌려줌
+    pub fn import(&self, notices: &[Notice], now: DateTime<FixedOffset>) -> rusqlite::Result<usize> {
+        let mut conn = self.conn.lock().unwrap();
+        let tx = conn.transaction()?;
+        let now = kst_time(now);
+        let mut imported = 0;
+        for n in notices {
+            let guid = n.guid();
+            let exists: bool = tx.query_row("SELECT EXISTS (SELECT 1 FROM notices WHERE guid = ?1)", [&guid], |r| r.get(0))?;
+            if exists {
+                continue;
+            }
+            let first_seen = n.first_seen.or(n.published).unwrap_or(now);
+            write_notice(&tx, &guid, n, first_seen, first_seen, first_seen)?;
+            add_revision(&tx, &guid, n, first_seen)?;
+            imported += 1;
+        }
+        tx.commit()?;
+        Ok(imported)
+    }
+
+    /// 글 1건과 수정 이력 (오래된 버전부터). 이력 기록 전부터 있던 글은 현재 내용 1개
+    pub fn history(&self, guid: &str) -> rusqlite::Result<Option<(StoredNotice, Vec<Revision>)>> {
+        let conn = self.conn.lock().unwrap();
+        let Some(stored) = conn
+            .query_row(&format!("SELECT {COLUMNS} FROM notices WHERE guid = ?1"), [guid], stored_from_row)
+            .optional()?
+        else {
+            return Ok(None);
+        };
+        let mut revisions = load_revisions(&conn, guid, usize::MAX)?;
+        revisions.reverse();
+        if revisions.is_empty() {
+            revisions.push(Revision::of(&stored.notice, 1, stored.notice.first_seen.unwrap_or(stored.last_changed)));
+        }
+        Ok(Some((stored, revisions)))
+    }
+
+    /// 마지막 수정에서 바뀐 항목 (수정된 적 없으면 None)
+    pub fn last_changes(&self, guid: &str) -> rusqlite::Result<Option<(DateTime<FixedOffset>, Vec<Change>)>> {
+        let conn = self.conn.lock().unwrap();
+        Ok(match load_revisions(&conn, guid, 2)?.as_slice() {
+            [last, prev] => Some((last.seen_at, prev.changes(last))),
+            _ => None,
+        })
+    }
+
+    /// 제목/본문/첨부 이름 검색. 관련도순, 같으면 최신순. 게시판에서 사라진 글도 포함
+    pub fn search(&self, query: &SearchQuery) -> rusqlite::Result<Vec<StoredNotice>> {
+        let Some(expr) = search::match_expr(&query.text) else {
+            return Ok(Vec::new());
+        };
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare_cached(&format!(
+            "SELECT {COLUMNS} FROM notice_search JOIN notices ON notices.guid = notice_search.notice_guid
+             WHERE notice_search MATCH ?1 AND (?2 IS NULL OR school = ?2)
+               AND (?3 IS NULL OR COALESCE(published, first_seen) >= ?3)
+               AND (?4 IS NULL OR COALESCE(published, first_seen) < ?4)
+             ORDER BY bm25(notice_search), COALESCE(published, first_seen) DESC
+             LIMIT ?5"
+        ))?;
+        let rows = stmt.query_map(
+            params![
+                expr,
+                query.school,
+                query.from_time().map(kst_time),
+                query.until_time().map(kst_time),
+                query.limit as i64
+            ],
+            stored_from_row,
+        )?;
+        rows.collect()
+    }
+
+    /// 이미 저장된 글의 GUID/URL (목록 페이지 넘김을 멈추는 기준)
+    pub fn known(&self, school: &str) -> rusqlite::Result<HashSet<String>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare_cached("SELECT guid, url FROM notices WHERE school = ?1")?;
+        let mut seen = HashSet::new();
+        for pair in stmt.query_map([school], |r| Ok((r.get::<_, String>(0)?, r.get::<_, String>(1)?)))? {
+            let (guid, url) = pair?;
+            seen.insert(guid);
+            seen.insert(url);
+        }
+        Ok(seen)
+    }
+}
+
+/// 저장/비교에 쓰는 시각은 모두 KST로 맞춤 (TEXT 비교가 맞으려면 오프셋이 하나여야 함)
+fn kst_time(t: DateTime<FixedOffset>) -> DateTime<FixedOffset> {
+    t.with_timezone(&kst())
+}
+
+fn user_version(conn: &Connection) -> rusqlite::Result<usize> {
+    conn.pragma_query_value(None, "user_version", |r| r.get(0))
+}
+
+/// 스키마를 최신으로 올리고 올리기 전 버전을 돌려줌
+fn migrate(conn: &mut Connection) -> rusqlite::Result<usize> {
+    let version = user_version(conn)?;
+    if version > MIGRATIONS.len() {
+        eprintln!(
+            "[STORE] 저장소 스키마(v{version})가 이 버전이 아는 것(v{})보다 새롭습니다.",
+            MIGRATIONS.len()
+        );
+    }
+    for (i, sql) in MIGRATIONS.iter().enumerate().skip(version) {
+        let tx = conn.transaction()?;
+        tx.execute_batch(sql)?;
+        tx.pragma_update(None, "user_version", i + 1)?;
+        tx.commit()?;
+    }
+    Ok(version)
+}
+
+/// 검색 색인 전체 다시 만들기
+fn reindex(conn: &mut Connection) -> rusqlite::Result<()> {
+    let tx = conn.transaction()?;
+    tx.execute("DELETE FROM notice_search", [])?;
+    let notices: Vec<Notice> = tx
+        .prepare(&format!("SELECT {COLUMNS} FROM notices"))?
+        .query_map([], stored_from_row)?
+        .map(|r| r.map(|s| s.notice))
+        .collect::<rusqlite::Result<_>>()?;
+    for n in &notices {
+        index_notice(&tx, &n.guid(), n)?;
+    }
+    tx.commit()
+}
+
+fn index_notice(conn: &Connection, guid: &str, n: &Notice) -> rusqlite::Result<()> {
+    conn.prepare_cached("DELETE FROM notice_search WHERE notice_guid = ?1")?
+        .execute([guid])?;
+    conn.prepare_cached("INSERT INTO notice_search (notice_guid, tokens) VALUES (?1, ?2)")?
+        .execute(params![guid, search::tokenize(&search::index_text(n)).join(" ")])?;
+    Ok(())
+}
+
+/// 목록만 읽은 실행이 상세 수집 결과(본문/작성자/첨부/게시 시각)를 지우지 않도록, 새 값이 없는 항목은 저장된 값 유지
+fn merge(old: &Notice, new: &Notice) -> Notice {
+    let mut n = new.clone();
+    if n.body_html.is_none() {
+        n.body_html = old.body_html.clone();
+        n.summary = n.summary.or_else(|| old.summary.clone());
+    }
+    n.author = n.author.or_else(|| old.author.clone());
+    n.views = n.views.or(old.views);
+    if n.attachments.is_empty() {
+        n.attachments = old.attachments.clone();
+    }
+    // 목록의 날짜(자정)보다 상세 페이지의 게시 시각이 정밀함
+    n.published = match (old.published, n.published) {
+        (Some(o), Some(p)) if o.date_naive() == p.date_naive() && p.time() == NaiveTime::MIN => Some(o),
+        (o, p) => p.or(o),
+    };
+    n.first_seen = old.first_seen;
+    n
+}
+
+/// 이번에 읽은 범위의 시작: 가장 오래된 일반 글의 다음 날 0시. 그날 글은 페이지 경계에서 잘렸을 수 있어 제외.
+/// 날짜가 있는 일반 글이 없으면 None (삭제 판단 안 함)
+fn window_start(notices: &[Notice]) -> Option<DateTime<FixedOffset>> {
+    let oldest = notices
+        .iter()
+        .filter(|n| !n.pinned)
+        .filter_map(|n| n.published)
+        .map(|d| kst_time(d).date_naive())
+        .min()?;
+    let next = oldest.checked_add_days(Days::new(1))?;
+    kst().from_local_datetime(&next.and_time(NaiveTime::MIN)).single()
+}
+
+/// 게시일이 `since` 이후인데 이번에 보지 못한 글에 삭제 표시
+fn mark_removed(
+    conn: &Connection,
+    school: &str,
+    since: DateTime<FixedOffset>,
+    now: DateTime<FixedOffset>,
+) -> rusqlite::Result<Vec<Tombstone>> {
+    let mut stmt = conn.prepare_cached(
+        "UPDATE notices SET removed_at = ?3
+         WHERE school = ?1 AND removed_at IS NULL AND last_seen < ?3 AND published >= ?2
+         RETURNING guid, title, url",
+    )?;
+    let rows = stmt.query_map(params![school, since, now], |r| {
+        Ok(Tombstone {
+            guid: r.get(0)?,
+            title: r.get(1)?,
+            url: r.get(2)?,
+            removed_at: now,
+        })
+    })?;
+    rows.collect()
+}
+
+fn write_notice(
+    conn: &Connection,
+    guid: &str,
+    n: &Notice,
+    first_seen: DateTime<FixedOffset>,
+    last_seen: DateTime<FixedOffset>,
+    last_changed: DateTime<FixedOffset>,
+) -> rusqlite::Result<()> {
+    let attachments = to_json(&n.attachments)?;
+    conn.prepare_cached(&format!(
+        "INSERT INTO notices (guid, {COLUMNS})
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, NULL)
+         ON CONFLICT (guid) DO UPDATE SET
+             board_id = excluded.board_id, title = excluded.title, category = excluded.category,
+             author = excluded.author, views = excluded.views, pinned = excluded.pinned,
+             date_raw = excluded.date_raw, published = excluded.published, url = excluded.url,
+             body_html = excluded.body_html, summary = excluded.summary, attachments = excluded.attachments,
+             last_seen = excluded.last_seen, last_changed = excluded.last_changed, removed_at = NULL"
+    ))?
+    .execute(params![
+        guid,
+        n.school,
+        n.id,
+        n.title,
+        n.category,
+        n.author,
+        n.views,
+        n.pinned,
+        n.date_raw,
+        n.published.map(kst_time),
+        n.url,
+        n.body_html,
+        n.summary,
+        attachments,
+        kst_time(first_seen),
+        kst_time(last_seen),
+        kst_time(last_changed),
+    ])?;
+    index_notice(conn, guid, n)
+}
+
+fn revision_count(conn: &Connection, guid: &str) -> rusqlite::Result<usize> {
+    conn.query_row("SELECT COUNT(*) FROM revisions WHERE guid = ?1", [guid], |r| r.get(0))
+}
+
+fn add_revision(conn: &Connection, guid: &str, n: &Notice, seen_at: DateTime<FixedOffset>) -> rusqlite::Result<()> {
+    let rev = Revision::of(n, revision_count(conn, guid)? + 1, seen_at);
+    conn.prepare_cached(
+        "INSERT INTO revisions (guid, seq, seen_at, title, published, body_hash, body_text, attachments)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+    )?
+    .execute(params![
+        guid,
+        rev.seq,
+        kst_time(rev.seen_at),
+        rev.title,
+        rev.published.map(kst_time),
+        rev.body_hash,
+        rev.body_text,
+        to_json(&rev.attachments)?,
+    ])?;
+    Ok(())
+}
+
+/// 최근 버전부터 최대 `limit`개
+fn load_revisions(conn: &Connection, guid: &str, limit: usize) -> rusqlite::Result<Vec<Revision>> {
+    let mut stmt = conn.prepare_cached(
+        "SELECT seq, seen_at, title, published, body_hash, body_text, attachments
+         FROM revisions WHERE guid = ?1 ORDER BY seq DESC LIMIT ?2",
+    )?;
+    let rows = stmt.query_map(params![guid, limit.min(i64::MAX as usize) as i64], |r| {
+        let attachments: String = r.get(6)?;
+        Ok(Revision {
+            seq: r.get(0)?,
+            seen_at: r.get(1)?,
+            title: r.get(2)?,
+            published: r.get(3)?,
+            body_hash: r.get(4)?,
+            body_text: r.get(5)?,
+            attachments: serde_json::from_str(&attachments).unwrap_or_default(),
+        })
+    })?;
+    rows.collect()
+}
+
+fn to_json(attachments: &[Attachment]) -> rusqlite::Result<String> {
+    serde_json::to_string(attachments).map_err(|e| rusqlite::Error::ToSqlConversionFailure(e.into()))
+}
+
+fn stored_from_row(r: &Row) -> rusqlite::Result<StoredNotice> {
+    let attachments: String = r.get("attachments")?;
+    let last_seen: DateTime<FixedOffset> = r.get("last_seen")?;
+    let last_changed: DateTime<FixedOffset> = r.get("last_changed")?;
+    let notice = Notice {
+        id: r.get("board_id")?,
+        school: r.get("school")?,
+        title: r.get("title")?,
+        category: r.get("category")?,
+        author: r.get("author")?,
+        views: r.get("views")?,
+        pinned: r.get("pinned")?,
+        date_raw: r.get("date_raw")?,
+        published: r.get("published")?,
+        url: r.get("url")?,
+        body_html: r.get("body_html")?,
+        summary: r.get("summary")?,
+        attachments: serde_json::from_str(&attachments).unwrap_or_default(),
+        fetched_at: last_seen,
+        first_seen: Some(r.get("first_seen")?),
+        last_changed: Some(last_changed),
+    };
+    Ok(StoredNotice {
+        notice,
+        last_seen,
+        last_changed,
+        removed_at: r.get("removed_at")?,
+    })
+}
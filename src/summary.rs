@@ -0,0 +1,89 @@
+// src/summary.rs
+// 원샷 실행 요약: 학교별 결과 표와 종료 코드 (0 = 모두 갱신, 2 = 일부 실패, 1 = 모두 실패)
+
+use std::fmt;
+
+use crate::health::HealthStatus;
+
+/// 학교 1곳의 파일 생성 결과
+#[derive(Debug, Clone, PartialEq)]
+pub enum SchoolOutcome {
+    /// rss.xml/atom.xml/feed.json/index.md를 새로 씀
+    Updated { items: usize, health: HealthStatus },
+    /// 이전 파일을 그대로 둠. `transient`면 네트워크/서버 쪽 일시 오류
+    Failed { reason: String, transient: bool },
+}
+
+impl SchoolOutcome {
+    pub fn is_ok(&self) -> bool {
+        matches!(self, SchoolOutcome::Updated { .. })
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            SchoolOutcome::Updated { health: HealthStatus::Ok, .. } => "갱신",
+            SchoolOutcome::Updated { .. } => "갱신(주의)",
+            SchoolOutcome::Failed { transient: true, .. } => "일시 오류 — 기존 유지",
+            SchoolOutcome::Failed { transient: false, .. } => "실패 — 기존 유지",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct RunSummary {
+    /// (학교 키, 학교 이름, 결과) — 등록 순서
+    pub schools: Vec<(&'static str, &'static str, SchoolOutcome)>,
+}
+
+impl RunSummary {
+    pub fn push(&mut self, key: &'static str, name: &'static str, outcome: SchoolOutcome) {
+        self.schools.push((key, name, outcome));
+    }
+
+    pub fn failed(&self) -> usize {
+        self.schools.iter().filter(|(_, _, o)| !o.is_ok()).count()
+    }
+
+    /// 0: 모두 갱신, 2: 일부 학교 실패(나머지는 배포 가능), 1: 모두 실패
+    pub fn exit_code(&self) -> i32 {
+        match self.failed() {
+            0 => 0,
+            n if n == self.schools.len() => 1,
+            _ => 2,
+        }
+    }
+
+    /// GitHub Actions 작업 요약(`$GITHUB_STEP_SUMMARY`)용 마크다운 표
+    pub fn markdown(&self) -> String {
+        let mut md = String::from("| 학교 | 결과 | 공지 수 | 비고 |\n| --- | --- | ---: | --- |\n");
+        for (_, name, outcome) in &self.schools {
+            let (items, note) = columns(outcome);
+            md.push_str(&format!("| {name} | {} | {items} | {} |\n", outcome.label(), note.replace('|', "\\|")));
+        }
+        md
+    }
+}
+
+fn columns(outcome: &SchoolOutcome) -> (String, &str) {
+    match outcome {
+        SchoolOutcome::Updated { items, .. } => (items.to_string(), ""),
+        SchoolOutcome::Failed { reason, .. } => ("-".to_string(), reason.as_str()),
+    }
+}
+
+impl fmt::Display for RunSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "── 실행 요약 ─────────────────────────────")?;
+        for (key, _, outcome) in &self.schools {
+            let (items, note) = columns(outcome);
+            writeln!(f, "{key:<10} {:<22} {items:>4}  {note}", outcome.label())?;
+        }
+        write!(
+            f,
+            "갱신 {} / 실패 {} (종료 코드 {})",
+            self.schools.len() - self.failed(),
+            self.failed(),
+            self.exit_code()
+        )
+    }
+}
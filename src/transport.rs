@@ -0,0 +1,251 @@
+// src/transport.rs
+// HTTP 전송 계층 교체 지점: 실제 네트워크(reqwest), 녹화(record), 재생(replay)
+//
+// 재생 모드는 녹화해 둔 응답(카세트 파일)만으로 스크래퍼를 돌리므로, 테스트가 학교 사이트 없이 돌아갑니다.
+
+use async_trait::async_trait;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+
+use crate::error::ScrapeError;
+
+/// GET 요청 1건
+pub struct TransportRequest<'a> {
+    pub school: &'static str,
+    pub url: &'a str,
+    pub headers: HeaderMap,
+}
+
+/// 응답 1건 (본문은 이미 텍스트로 읽은 상태)
+pub struct TransportResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: String,
+}
+
+impl TransportResponse {
+    pub fn header(&self, name: impl reqwest::header::AsHeaderName) -> Option<String> {
+        self.headers
+            .get(name)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string)
+    }
+}
+
+#[async_trait]
+pub trait Transport: Send + Sync {
+    async fn get(&self, req: TransportRequest<'_>) -> Result<TransportResponse, ScrapeError>;
+}
+
+/// 실제 네트워크
+pub struct ReqwestTransport {
+    client: Client,
+}
+
+impl ReqwestTransport {
+    pub fn new(client: Client) -> Self {
+        ReqwestTransport { client }
+    }
+}
+
+#[async_trait]
+impl Transport for ReqwestTransport {
+    async fn get(&self, req: TransportRequest<'_>) -> Result<TransportResponse, ScrapeError> {
+        let res = self
+            .client
+            .get(req.url)
+            .headers(req.headers)
+            .send()
+            .await
+            .map_err(|source| ScrapeError::Transport {
+                school: req.school,
+                url: req.url.to_string(),
+                source,
+            })?;
+        let status = res.status();
+        let headers = res.headers().clone();
+        // 본문을 받다 끊기거나 시간이 다 된 것은 전송 오류(재시도 대상), 문자셋 디코딩 실패만 `Decode`
+        let body = res.text().await.map_err(|source| {
+            let url = req.url.to_string();
+            if source.is_timeout() || source.is_connect() || source.is_body() {
+                ScrapeError::Transport { school: req.school, url, source }
+            } else {
+                ScrapeError::Decode { school: req.school, url, source }
+            }
+        })?;
+        Ok(TransportResponse { status, headers, body })
+    }
+}
+
+/* ───────────── 카세트 (녹화/재생) ───────────── */
+
+/// 카세트에 남기는 응답 헤더. 쿠키 등은 저장하지 않음
+const RECORDED_HEADERS: &[&str] = &["content-type", "etag", "last-modified", "retry-after", "location"];
+
+/// 녹화된 요청/응답 1쌍
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Interaction {
+    pub url: String,
+    pub status: u16,
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+    pub body: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CassetteMode {
+    /// 실제로 요청하고 응답을 카세트에 저장
+    Record,
+    /// 카세트에 있는 응답만 돌려줌 (네트워크 사용 안 함)
+    Replay,
+}
+
+impl FromStr for CassetteMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "record" => Ok(CassetteMode::Record),
+            "replay" => Ok(CassetteMode::Replay),
+            other => Err(format!("cassette 모드는 record/replay 중 하나여야 합니다: {other}")),
+        }
+    }
+}
+
+impl fmt::Display for CassetteMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            CassetteMode::Record => "record",
+            CassetteMode::Replay => "replay",
+        })
+    }
+}
+
+/// URL 단위로 응답을 녹화/재생하는 JSON 파일 (`[{url, status, headers, body}, ...]`)
+pub struct Cassette {
+    path: PathBuf,
+    /// 녹화 모드일 때 실제 요청을 보낼 전송 계층
+    inner: Option<Arc<dyn Transport>>,
+    interactions: Mutex<Vec<Interaction>>,
+}
+
+impl Cassette {
+    /// 재생 전용. 파일이 없으면 오류
+    pub fn replay(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let interactions = load_interactions(&path)?;
+        Ok(Cassette {
+            path,
+            inner: None,
+            interactions: Mutex::new(interactions),
+        })
+    }
+
+    /// `inner`로 실제 요청 후 카세트에 저장. 같은 URL은 새 응답으로 교체
+    pub fn record(path: impl AsRef<Path>, inner: Arc<dyn Transport>) -> Self {
+        let path = path.as_ref().to_path_buf();
+        let interactions = load_interactions(&path).unwrap_or_default();
+        Cassette {
+            path,
+            inner: Some(inner),
+            interactions: Mutex::new(interactions),
+        }
+    }
+
+    pub fn open(path: impl AsRef<Path>, mode: CassetteMode, inner: Arc<dyn Transport>) -> io::Result<Self> {
+        match mode {
+            CassetteMode::Replay => Cassette::replay(path),
+            CassetteMode::Record => Ok(Cassette::record(path, inner)),
+        }
+    }
+
+    fn save(&self, interactions: &[Interaction]) {
+        let result = self
+            .path
+            .parent()
+            .map_or(Ok(()), fs::create_dir_all)
+            .and_then(|_| {
+                let json = serde_json::to_string_pretty(interactions).map_err(io::Error::other)?;
+                fs::write(&self.path, json)
+            });
+        if let Err(e) = result {
+            eprintln!("[CASSETTE] 저장 실패 ({}): {e}", self.path.display());
+        }
+    }
+}
+
+fn load_interactions(path: &Path) -> io::Result<Vec<Interaction>> {
+    let raw = fs::read_to_string(path)?;
+    serde_json::from_str(&raw).map_err(io::Error::other)
+}
+
+#[async_trait]
+impl Transport for Cassette {
+    async fn get(&self, req: TransportRequest<'_>) -> Result<TransportResponse, ScrapeError> {
+        let Some(inner) = &self.inner else {
+            let found = self
+                .interactions
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|i| i.url == req.url)
+                .cloned();
+            return match found {
+                Some(i) => Ok(i.into_response()),
+                // 녹화되지 않은 요청은 404로 돌려줌 (robots.txt, 마지막 다음 페이지 등)
+                None => Ok(TransportResponse {
+                    status: StatusCode::NOT_FOUND,
+                    headers: HeaderMap::new(),
+                    body: String::new(),
+                }),
+            };
+        };
+
+        let url = req.url.to_string();
+        let res = inner.get(req).await?;
+        // 304는 본문이 없어 재생할 수 없으므로 녹화하지 않음 (HTTP 캐시가 켜져 있었다는 뜻)
+        if res.status == StatusCode::NOT_MODIFIED {
+            eprintln!("[CASSETTE] 304 응답은 녹화하지 않습니다: {url}");
+            return Ok(res);
+        }
+        let interaction = Interaction {
+            url: url.clone(),
+            status: res.status.as_u16(),
+            headers: RECORDED_HEADERS
+                .iter()
+                .filter_map(|name| Some((name.to_string(), res.header(*name)?)))
+                .collect(),
+            body: res.body.clone(),
+        };
+        let mut interactions = self.interactions.lock().unwrap();
+        interactions.retain(|i| i.url != url);
+        interactions.push(interaction);
+        self.save(&interactions);
+        eprintln!("[CASSETTE] 녹화: {url} ({})", res.status);
+        Ok(res)
+    }
+}
+
+impl Interaction {
+    fn into_response(self) -> TransportResponse {
+        let mut headers = HeaderMap::new();
+        for (name, value) in &self.headers {
+            if let (Ok(n), Ok(v)) = (HeaderName::from_bytes(name.as_bytes()), HeaderValue::from_str(value)) {
+                headers.insert(n, v);
+            }
+        }
+        TransportResponse {
+            status: StatusCode::from_u16(self.status).unwrap_or(StatusCode::OK),
+            headers,
+            body: self.body,
+        }
+    }
+}
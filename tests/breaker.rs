@@ -0,0 +1,44 @@
+// tests/breaker.rs
+// 회로 차단기: half-open에서는 시험 요청 1건만 보내고, 그 결과로 닫히거나 다시 열림
+
+use std::time::Duration;
+use univ_crawler::breaker::CircuitBreakers;
+
+const COOLDOWN: Duration = Duration::from_millis(50);
+
+fn tripped() -> CircuitBreakers {
+    let breakers = CircuitBreakers::new(1, COOLDOWN);
+    assert!(breakers.check("test").is_ok());
+    breakers.record_failure("test", "HTTP 503");
+    assert!(breakers.check("test").is_err());
+    breakers
+}
+
+#[test]
+fn half_open_lets_one_probe_through() {
+    let breakers = tripped();
+    std::thread::sleep(COOLDOWN);
+
+    assert!(breakers.check("test").is_ok(), "시험 요청");
+    assert!(breakers.check("test").is_err(), "시험 중인 동안 다른 요청은 막힘");
+    breakers.record_success("test");
+    assert!(breakers.check("test").is_ok());
+    assert!(breakers.check("test").is_ok());
+}
+
+#[test]
+fn failed_probe_reopens_and_released_probe_allows_next() {
+    let breakers = tripped();
+    std::thread::sleep(COOLDOWN);
+    assert!(breakers.check("test").is_ok());
+    breakers.record_failure("test", "HTTP 503");
+    assert_eq!(breakers.snapshot()[0].state, "open");
+    assert!(breakers.check("test").is_err());
+
+    std::thread::sleep(COOLDOWN);
+    assert!(breakers.check("test").is_ok());
+    // 사이트에 닿지 않고 끝난 시험 요청은 다음 요청에 시험을 넘김
+    breakers.release("test");
+    assert!(breakers.check("test").is_ok());
+    assert!(breakers.check("test").is_err());
+}
@@ -0,0 +1,65 @@
+// tests/cassette.rs
+// 카세트 녹화: 녹화 중에는 HTTP 캐시를 끄고, 재생할 수 없는 304 응답은 남기지 않음
+
+use async_trait::async_trait;
+use reqwest::header::HeaderMap;
+use reqwest::StatusCode;
+use std::path::PathBuf;
+use std::sync::Arc;
+use univ_crawler::config::Config;
+use univ_crawler::error::ScrapeError;
+use univ_crawler::transport::{Cassette, Transport, TransportRequest, TransportResponse};
+
+/// URL이 `/fresh`로 끝나면 200, 아니면 304로 답하는 서버
+struct Upstream;
+
+#[async_trait]
+impl Transport for Upstream {
+    async fn get(&self, req: TransportRequest<'_>) -> Result<TransportResponse, ScrapeError> {
+        let (status, body) = if req.url.ends_with("/fresh") {
+            (StatusCode::OK, "<html>목록</html>")
+        } else {
+            (StatusCode::NOT_MODIFIED, "")
+        };
+        Ok(TransportResponse {
+            status,
+            headers: HeaderMap::new(),
+            body: body.to_string(),
+        })
+    }
+}
+
+fn request(url: &str) -> TransportRequest<'_> {
+    TransportRequest {
+        school: "seoul",
+        url,
+        headers: HeaderMap::new(),
+    }
+}
+
+#[tokio::test]
+async fn recording_skips_not_modified_responses() {
+    let path = std::env::temp_dir().join(format!("univ-crawler-cassette-{}.json", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    let recorder = Cassette::record(&path, Arc::new(Upstream));
+    recorder.get(request("https://example.ac.kr/fresh")).await.unwrap();
+    let res = recorder.get(request("https://example.ac.kr/cached")).await.unwrap();
+    assert_eq!(res.status, StatusCode::NOT_MODIFIED);
+
+    let replay = Cassette::replay(&path).unwrap();
+    let fresh = replay.get(request("https://example.ac.kr/fresh")).await.unwrap();
+    assert_eq!((fresh.status, fresh.body.as_str()), (StatusCode::OK, "<html>목록</html>"));
+    let cached = replay.get(request("https://example.ac.kr/cached")).await.unwrap();
+    assert_eq!(cached.status, StatusCode::NOT_FOUND);
+    let _ = std::fs::remove_file(&path);
+}
+
+#[test]
+fn record_flag_disables_http_cache() {
+    let args = |a: &[&str]| a.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+    let config = Config::default().apply_args(&args(&["--replay", "x.json"]));
+    assert!(config.http.cache_dir.is_some());
+    let config = Config::default().apply_args(&args(&["--record", "x.json"]));
+    assert_eq!(config.http.cache_dir, None::<PathBuf>);
+    assert!(config.http.is_recording());
+}
@@ -0,0 +1,66 @@
+// tests/feed_cache.rs
+// 서버 피드 캐시: TTL 안/밖 조회, 실패 시 지난 피드 유지, 백그라운드 갱신 중복 방지
+
+use std::time::Duration;
+use univ_crawler::feed_cache::{FeedCache, FeedFormat, Lookup};
+use univ_crawler::model::PinnedMode;
+
+#[test]
+fn serves_last_good_feed_when_refresh_fails() {
+    let cache = FeedCache::new(Duration::from_secs(600));
+    assert!(matches!(cache.get("seoul", PinnedMode::Include, FeedFormat::Rss), Lookup::Missing));
+
+    cache.put("seoul", PinnedMode::Include, FeedFormat::Rss, "<rss>v1</rss>".into());
+    cache.put("seoul", PinnedMode::Only, FeedFormat::Rss, "<rss>pinned</rss>".into());
+    let Lookup::Fresh(feed) = cache.get("seoul", PinnedMode::Include, FeedFormat::Rss) else {
+        panic!("TTL 안이면 Fresh");
+    };
+    assert_eq!(feed.xml, "<rss>v1</rss>");
+    assert!(matches!(cache.get("seoul", PinnedMode::Exclude, FeedFormat::Rss), Lookup::Missing));
+    cache.put("seoul", PinnedMode::Include, FeedFormat::Atom, "<feed/>".into());
+    let mut variants = cache.variants("seoul");
+    variants.sort_by_key(|(pinned, format)| (pinned.to_string(), format.file_name()));
+    assert_eq!(
+        variants,
+        [
+            (PinnedMode::Include, FeedFormat::Atom),
+            (PinnedMode::Include, FeedFormat::Rss),
+            (PinnedMode::Only, FeedFormat::Rss)
+        ]
+    );
+
+    // 실패해도 XML은 그대로, 오류만 기록
+    cache.fail("seoul", "upstream timed out");
+    let Lookup::Fresh(feed) = cache.get("seoul", PinnedMode::Only, FeedFormat::Rss) else {
+        panic!("실패 기록 후에도 TTL 동안은 지난 피드");
+    };
+    assert_eq!(feed.xml, "<rss>pinned</rss>");
+    assert_eq!(feed.last_error.as_deref(), Some("upstream timed out"));
+
+    cache.put("seoul", PinnedMode::Only, FeedFormat::Rss, "<rss>pinned v2</rss>".into());
+    let Lookup::Fresh(feed) = cache.get("seoul", PinnedMode::Only, FeedFormat::Rss) else {
+        panic!("다시 성공하면 Fresh");
+    };
+    assert!(feed.last_error.is_none());
+
+    // 같은 학교의 새로 수집은 한 번에 하나만
+    assert!(cache.begin_refresh("seoul"));
+    assert!(!cache.begin_refresh("seoul"));
+    cache.end_refresh("seoul");
+    assert!(cache.begin_refresh("seoul"));
+}
+
+#[test]
+fn expired_feed_is_stale_and_due_for_refresh() {
+    let cache = FeedCache::new(Duration::from_millis(20));
+    cache.put("dongduk", PinnedMode::Include, FeedFormat::Rss, "<rss/>".into());
+    assert!(cache.due(Duration::from_secs(60)).is_empty());
+    std::thread::sleep(Duration::from_millis(30));
+    assert!(matches!(cache.get("dongduk", PinnedMode::Include, FeedFormat::Rss), Lookup::Stale(_)));
+    assert_eq!(cache.due(Duration::from_millis(20)), ["dongduk"]);
+
+    // TTL 0이면 캐시하지 않음
+    let off = FeedCache::new(Duration::ZERO);
+    off.put("dongduk", PinnedMode::Include, FeedFormat::Rss, "<rss/>".into());
+    assert!(matches!(off.get("dongduk", PinnedMode::Include, FeedFormat::Rss), Lookup::Missing));
+}
@@ -0,0 +1,297 @@
+// tests/golden.rs
+// 카세트 형식의 합성 응답(tests/fixtures/cassettes/*.json)만으로 학교별 파싱과 피드(RSS/Atom/JSON) 출력을 확인합니다.
+// (네트워크 사용 안 함)
+//
+// 카세트는 실제 사이트에서 녹화한 것이 아니라 손으로 쓴 HTML입니다.
+// - `<학교>.json`: 행 3개짜리 최소 목록(+동덕 상세 1건). 파서가 기대하는 선택자와 링크 형태별 분기만 담음
+// - `<학교>-board.json`: 각 학교 게시판 목록 페이지의 전체 구조(머리글/검색 폼/페이지 이동/스크립트)를 본뜬 1페이지 10행
+// 피드 출력이 의도적으로 바뀌었으면 `UPDATE_GOLDEN=1 cargo test`로 tests/golden/*을 다시 씁니다.
+// 실제 응답으로 바꾸려면 `cargo run -- --oneshot --record <파일>`로 녹화한 뒤(녹화 중에는 HTTP 캐시를 쓰지 않음)
+// 해당 학교의 항목만 tests/fixtures/cassettes/<학교>.json으로 옮깁니다. (한 번 실행에 모든 학교가 한 파일에 담김)
+
+use std::sync::Arc;
+
+use atom_syndication::WriteConfig;
+
+use univ_crawler::config::HttpConfig;
+use univ_crawler::model::{extract_date_token, parse_size};
+use univ_crawler::schools::detail::parse_detail;
+use univ_crawler::schools::{self, dongduk, seoul, FetchOptions, HttpClient, Notice, ScrapeError};
+use univ_crawler::transport::Cassette;
+
+fn replay_client(cassette: &str) -> HttpClient {
+    let config = HttpConfig {
+        respect_robots: false,
+        host_rps: 0.0,
+        max_retries: 0,
+        ..HttpConfig::default()
+    };
+    let path = format!("{}/tests/fixtures/cassettes/{cassette}.json", env!("CARGO_MANIFEST_DIR"));
+    let cassette = Cassette::replay(&path).unwrap_or_else(|e| panic!("{path}: {e}"));
+    HttpClient::with_transport(&config, Arc::new(cassette))
+}
+
+async fn fetch(school: &str, opts: FetchOptions) -> Result<Vec<Notice>, ScrapeError> {
+    let school = schools::find(school).expect("등록된 학교");
+    school.fetch_notices(&replay_client(school.key()), &opts).await
+}
+
+fn rss_xml(school: &str, notices: &[Notice]) -> String {
+    let channel = schools::find(school).unwrap().create_rss(notices);
+    let mut out = Vec::new();
+    channel.pretty_write_to(&mut out, b' ', 2).unwrap();
+    String::from_utf8(out).unwrap() + "\n"
+}
+
+/// 피드 자신의 주소(Atom self 링크, JSON Feed feed_url)를 만들 공개 주소
+const PUBLIC_URL: &str = "https://feeds.example.com";
+
+fn atom_xml(school: &str, notices: &[Notice]) -> String {
+    let feed = schools::find(school).unwrap().create_atom(notices, PUBLIC_URL);
+    let config = WriteConfig {
+        write_document_declaration: true,
+        indent_size: Some(2),
+    };
+    String::from_utf8(feed.write_with_config(Vec::new(), config).unwrap()).unwrap() + "\n"
+}
+
+fn assert_golden(name: &str, actual: &str) {
+    let path = format!("{}/tests/golden/{name}", env!("CARGO_MANIFEST_DIR"));
+    if std::env::var_os("UPDATE_GOLDEN").is_some() {
+        std::fs::write(&path, actual).unwrap();
+        return;
+    }
+    let expected = std::fs::read_to_string(&path).unwrap_or_else(|e| panic!("{path}: {e}"));
+    assert_eq!(actual, expected, "{name}가 기대값과 다릅니다 (의도한 변경이면 UPDATE_GOLDEN=1)");
+}
+
+fn summary(notices: &[Notice]) -> Vec<(Option<&str>, &str, bool, Option<String>)> {
+    notices
+        .iter()
+        .map(|n| {
+            let date = n.published.map(|d| d.format("%Y-%m-%d").to_string());
+            (n.id.as_deref(), n.title.as_str(), n.pinned, date)
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn sookmyung_list_page() {
+    let notices = fetch("sookmyung", FetchOptions::default()).await.unwrap();
+    assert_eq!(
+        summary(&notices),
+        vec![
+            (Some("187001"), "[학사] 2025학년도 1학기 수강신청 안내", true, Some("2025-02-03".into())),
+            (Some("187120"), "[장학] 2025년 국가장학금 2차 신청", false, Some("2025-02-10".into())),
+            (Some("187098"), "도서관 휴관 안내", false, Some("2025-02-07".into())),
+        ]
+    );
+    assert_eq!(notices[0].author.as_deref(), Some("학사팀"));
+    assert_eq!(notices[0].views, Some(1204));
+    assert_eq!(
+        notices[1].url,
+        "https://www.sookmyung.ac.kr/kr/news/important-notice.do?mode=view&articleNo=187120&article.offset=0&articleLimit=10"
+    );
+    assert_golden("sookmyung.xml", &rss_xml("sookmyung", &notices));
+}
+
+#[tokio::test]
+async fn dongduk_list_page_with_details() {
+    let opts = FetchOptions {
+        details: true,
+        ..FetchOptions::default()
+    };
+    let notices = fetch("dongduk", opts).await.unwrap();
+    assert_eq!(
+        summary(&notices),
+        vec![
+            (Some("90378"), "2025학년도 1학기 휴·복학 신청 안내", true, Some("2025-01-20".into())),
+            (Some("90412"), "[장학] 교내 근로장학생 모집", false, Some("2025-02-05".into())),
+            (Some("90401"), "2025학년도 전과 신청 결과 안내", false, Some("2025-02-03".into())),
+        ]
+    );
+    // onclick="fn_goView(..)" 행은 GET 링크로 전개
+    assert_eq!(
+        notices[0].url,
+        "https://www.dongduk.ac.kr/www/contents/kor-noti.do?schM=view&id=90378&etc1=8901"
+    );
+
+    // 상세 페이지가 녹화된 글만 본문/작성자/시각/첨부가 채워짐
+    let detail = &notices[1];
+    assert_eq!(detail.author.as_deref(), Some("학생처"));
+    assert_eq!(detail.pub_date_rfc2822(), "Wed, 5 Feb 2025 14:30:00 +0900");
+    assert!(detail
+        .body_html
+        .as_deref()
+        .unwrap()
+        .contains("src=\"https://www.dongduk.ac.kr/upload/board/guide.png\""));
+    assert_eq!(detail.attachments.len(), 1);
+    assert_eq!(detail.attachments[0].name, "근로장학 신청서.hwp");
+    assert_eq!(detail.attachments[0].size, Some(48 * 1024));
+    assert!(notices[2].body_html.is_none());
+
+    assert_golden("dongduk.xml", &rss_xml("dongduk", &notices));
+    // Atom: 첨부는 enclosure 링크, 본문은 html content, 피드 자신의 주소는 self 링크
+    let atom = atom_xml("dongduk", &notices);
+    assert!(atom.contains(r#"href="https://feeds.example.com/school-rss/dongduk/atom.xml" rel="self""#));
+    assert_golden("dongduk.atom.xml", &atom);
+    // JSON Feed: 본문이 있으면 content_html + 평문 content_text, 첨부는 attachments
+    let feed = schools::find("dongduk").unwrap().create_json_feed(&notices, PUBLIC_URL);
+    assert_eq!(feed.feed_url, "https://feeds.example.com/school-rss/dongduk/feed.json");
+    let json = feed.to_json() + "\n";
+    assert_golden("dongduk.feed.json", &json);
+}
+
+#[tokio::test]
+async fn seoul_list_page() {
+    let notices = fetch("seoul", FetchOptions::default()).await.unwrap();
+    assert_eq!(
+        summary(&notices),
+        vec![
+            (Some("506633"), "2025학년도 1학기 등록금 납부 안내", true, Some("2025-02-12".into())),
+            (Some("506601"), "[수업] 계절학기 성적 확인 안내", false, Some("2025-02-06".into())),
+            (Some("506590"), "졸업예정자 학위수여식 안내", false, Some("2025-02-04".into())),
+        ]
+    );
+    assert_eq!(
+        notices[0].url,
+        "https://www.swu.ac.kr/front/boardview.do?pkid=506633&menuGubun=1&siteGubun=1&bbsConfigFK=4"
+    );
+    assert_golden("seoul.xml", &rss_xml("seoul", &notices));
+}
+
+#[tokio::test]
+async fn full_board_pages_parse_every_row() {
+    // (학교, 고정 글 id, 첫 일반 글 (id, 제목, 날짜))
+    let cases = [
+        ("sookmyung", ["187001", "186950"], ("187133", "2025학년도 1학기 학생증 발급 일정", "2025-02-11")),
+        ("dongduk", ["90378", "90355"], ("90420", "2025학년도 1학기 수강신청 일정 및 유의사항", "2025-02-06")),
+        ("seoul", ["506633", "506500"], ("506640", "2025학년도 1학기 수강정정 기간 안내", "2025-02-13")),
+    ];
+    for (key, pinned, first) in cases {
+        let school = schools::find(key).unwrap();
+        let client = replay_client(&format!("{key}-board"));
+        let (result, stats) = school.fetch_with_stats(&client, &FetchOptions::default()).await;
+        let notices = result.unwrap_or_else(|e| panic!("{key}: {e}"));
+
+        // 머리글/페이지 이동 등 목록 밖 요소는 행으로 세지 않음
+        assert_eq!((stats.rows, stats.missing_title, stats.missing_url), (10, 0, 0), "{key}");
+        assert_eq!(notices.len(), 10, "{key}");
+        let pinned_ids: Vec<_> = notices.iter().filter(|n| n.pinned).filter_map(|n| n.id.as_deref()).collect();
+        assert_eq!(pinned_ids, pinned, "{key}");
+        assert_eq!(summary(&notices)[2], (Some(first.0), first.1, false, Some(first.2.into())), "{key}");
+        for n in &notices {
+            assert!(n.id.is_some() && n.published.is_some(), "{key}: {n:?}");
+            assert!(n.url.starts_with(school.base_url()), "{key}: {}", n.url);
+            assert_eq!(n.title, n.title.trim(), "{key}");
+        }
+    }
+}
+
+#[tokio::test]
+async fn later_page_failure_keeps_first_page() {
+    // 2페이지는 녹화돼 있지 않음 (404) → 1페이지 결과만 반환
+    let opts = FetchOptions {
+        max_pages: 3,
+        ..FetchOptions::default()
+    };
+    let notices = fetch("seoul", opts).await.unwrap();
+    assert_eq!(notices.len(), 3);
+}
+
+#[tokio::test]
+async fn first_page_failure_is_an_error() {
+    let client = replay_client("seoul");
+    let err = schools::find("sookmyung")
+        .unwrap()
+        .fetch_notices(&client, &FetchOptions::default())
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ScrapeError::HttpStatus { school: "sookmyung", .. }), "{err}");
+}
+
+#[test]
+fn parse_fn_go_view_cases() {
+    assert_eq!(
+        dongduk::parse_fn_go_view("fn_goView('90378', false, '8901', '')"),
+        Some(("90378".to_string(), "8901".to_string()))
+    );
+    assert_eq!(
+        dongduk::parse_fn_go_view("javascript:fn_goView('1',true,'2','');return false;"),
+        Some(("1".to_string(), "2".to_string()))
+    );
+    assert_eq!(dongduk::parse_fn_go_view("fn_goView('90378')"), None);
+    assert_eq!(dongduk::parse_fn_go_view("location.href='/x'"), None);
+}
+
+#[test]
+fn parse_board_move_cases() {
+    assert_eq!(
+        seoul::parse_board_move("boardMove('/front/boardview.do','506633');"),
+        Some(("/front/boardview.do".to_string(), "506633".to_string()))
+    );
+    assert_eq!(
+        seoul::parse_board_move("javascript:boardMove( '/front/boardview.do' , '1' )"),
+        Some(("/front/boardview.do".to_string(), "1".to_string()))
+    );
+    assert_eq!(seoul::parse_board_move("boardMove('/front/boardview.do')"), None);
+    assert_eq!(seoul::parse_board_move("noop"), None);
+}
+
+#[test]
+fn extract_date_token_cases() {
+    assert_eq!(extract_date_token("2025.02.03"), Some((2025, 2, 3)));
+    assert_eq!(extract_date_token("2025-2-3"), Some((2025, 2, 3)));
+    assert_eq!(extract_date_token("2025/02/03."), Some((2025, 2, 3)));
+    // 조회수/글자와 섞여 있어도 날짜만
+    assert_eq!(extract_date_token("조회 1,204 등록일 2024.12.31 14:00"), Some((2024, 12, 31)));
+    // 존재하지 않는 날짜, 2자리 연도, 날짜 없음
+    assert_eq!(extract_date_token("2025.02.30"), None);
+    assert_eq!(extract_date_token("25.02.03"), None);
+    assert_eq!(extract_date_token("N/A"), None);
+}
+
+#[test]
+fn detail_links_resolve_against_page_url() {
+    let page = "https://www.swu.ac.kr/front/boardview.do?pkid=506633&bbsConfigFK=4";
+    let html = r##"<div class="view_contents">
+        <img src="//cdn.swu.ac.kr/img/a.png">
+        <img src='/upload/b.png'>
+        <img src=c.png alt=c>
+        <img data-src="/lazy.png" src="https://other.example/d.png">
+        <a href="?pkid=1">이전 글</a> <a href="#top">맨 위</a> <a href="mailto:haksa@swu.ac.kr">문의</a>
+      </div>
+      <div class="file"><a href="//files.swu.ac.kr/down.do?id=7">안내문.pdf</a> (1.2MB)</div>"##;
+    let detail = parse_detail(html, &["view_contents"], "https://www.swu.ac.kr", page);
+    let body = detail.body_html.unwrap();
+    for expected in [
+        r#"src="https://cdn.swu.ac.kr/img/a.png""#,
+        r#"src="https://www.swu.ac.kr/upload/b.png""#,
+        r#"src="https://www.swu.ac.kr/front/c.png""#,
+        r#"data-src="/lazy.png" src="https://other.example/d.png""#,
+        r#"href="https://www.swu.ac.kr/front/boardview.do?pkid=1""#,
+        r##"href="#top""##,
+        r#"href="mailto:haksa@swu.ac.kr""#,
+    ] {
+        assert!(body.contains(expected), "{expected} 없음: {body}");
+    }
+    assert!(!body.contains("swu.ac.kr//"), "{body}");
+    assert_eq!(detail.attachments[0].url, "https://files.swu.ac.kr/down.do?id=7");
+}
+
+#[test]
+fn parse_size_cases() {
+    assert_eq!(parse_size(" (48KB)"), Some(48 * 1024));
+    assert_eq!(parse_size("[1.5 MB]"), Some(1572864));
+    assert_eq!(parse_size("2,048 bytes"), Some(2048));
+    assert_eq!(parse_size("(512 B)"), Some(512));
+    // 이름 속 B/KB/GB는 크기가 아님
+    assert_eq!(parse_size("B형 신청서.hwp"), None);
+    assert_eq!(parse_size("2B형 신청서.hwp"), None);
+    assert_eq!(parse_size("KB국민은행 계좌 안내"), None);
+    assert_eq!(parse_size("2025 GB 안내.pdf"), None);
+    assert_eq!(parse_size("(최종)"), None);
+    // 괄호 안의 크기 표기만 읽음
+    assert_eq!(parse_size("KB국민은행 계좌 안내.pdf (12KB)"), Some(12 * 1024));
+    assert_eq!(parse_size("(수정) B형 신청서.hwp [3 MB]"), Some(3 << 20));
+}
@@ -0,0 +1,59 @@
+// tests/health.rs
+// 목록 페이지 헬스 리포트: 정상 페이지는 통과, 개편된 페이지는 구조 지문 변화와 함께 Broken
+
+use univ_crawler::health::{Baseline, HealthReport, HealthStatus, ListStats};
+use univ_crawler::schools::seoul;
+use univ_crawler::transport::Interaction;
+
+const LIST_URL: &str = "https://www.swu.ac.kr/front/boardlist.do?currentPage=1";
+
+fn recorded_seoul_list() -> String {
+    let path = format!("{}/tests/fixtures/cassettes/seoul.json", env!("CARGO_MANIFEST_DIR"));
+    let interactions: Vec<Interaction> = serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+    interactions.into_iter().next().unwrap().body
+}
+
+fn check(html: &str, baseline: Option<Baseline>) -> HealthReport {
+    let mut stats = ListStats::default();
+    let result = seoul::parse_list(html, LIST_URL, &mut stats);
+    HealthReport::evaluate("seoul", &stats, result.as_deref(), baseline)
+}
+
+#[test]
+fn recorded_page_is_healthy_and_fingerprint_is_stable() {
+    let html = recorded_seoul_list();
+    let first = check(&html, None);
+    assert_eq!(first.status, HealthStatus::Ok, "{first}");
+    assert_eq!((first.rows, first.parsed), (3, 3));
+
+    // 글 내용이 바뀌어도 구조 지문은 같음
+    let edited = html.replace("졸업예정자 학위수여식 안내", "졸업예정자 학위수여식 안내 (수정)");
+    let second = check(&edited, Some(first.to_baseline()));
+    assert_eq!(second.status, HealthStatus::Ok, "{second}");
+    assert_eq!(second.fingerprint, first.fingerprint);
+}
+
+#[test]
+fn redesigned_page_is_broken_with_diagnosis() {
+    let baseline = check(&recorded_seoul_list(), None).to_baseline();
+    // 개편: 제목 칸이 td.subject > a로 바뀌고 날짜 칸이 사라짐
+    let redesigned = r#"<table><tbody>
+        <tr><td>845</td><td class="subject"><a href="/front/boardview.do?pkid=1">새 공지</a></td></tr>
+        <tr><td>844</td><td class="subject"><a href="/front/boardview.do?pkid=2">새 공지 2</a></td></tr>
+    </tbody></table>"#;
+    let report = check(redesigned, Some(baseline));
+    assert_eq!(report.status, HealthStatus::Broken, "{report}");
+    assert_ne!(report.fingerprint, report.baseline.as_ref().unwrap().fingerprint);
+    assert!(report.problems[0].starts_with("사이트 개편 의심"), "{report}");
+}
+
+#[test]
+fn javascript_fallbacks_and_missing_dates_are_reported() {
+    let html = r#"<table><tbody>
+        <tr><td>2</td><td class="title"><div><a onclick="goSomewhere(1)">링크 없는 글</a></div></td><td>2025.02.01</td></tr>
+        <tr><td>1</td><td class="title"><div><a href="/front/boardview.do?pkid=9">날짜 없는 글</a></div></td><td>-</td></tr>
+    </tbody></table>"#;
+    let report = check(html, None);
+    assert_eq!(report.status, HealthStatus::Warn, "{report}");
+    assert_eq!((report.javascript_urls, report.missing_date), (1, 1));
+}
@@ -0,0 +1,115 @@
+// tests/polite.rs
+// robots.txt 해석: crawl-delay 값 검증과 상한, robots.txt 응답 상태별 처리 (허용/금지/일시적 오류)
+
+use async_trait::async_trait;
+use reqwest::header::HeaderMap;
+use reqwest::StatusCode;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use univ_crawler::config::HttpConfig;
+use univ_crawler::error::ScrapeError;
+use univ_crawler::http::HttpClient;
+use univ_crawler::polite::{Robots, MAX_CRAWL_DELAY, ROBOTS_AGENT};
+use univ_crawler::transport::{Transport, TransportRequest, TransportResponse};
+
+fn crawl_delay(value: &str) -> Option<Duration> {
+    Robots::parse(&format!("User-agent: *\nDisallow: /admin\nCrawl-delay: {value}\n"), ROBOTS_AGENT).crawl_delay
+}
+
+#[test]
+fn crawl_delay_accepts_normal_values() {
+    assert_eq!(crawl_delay("2.5"), Some(Duration::from_millis(2500)));
+    assert_eq!(crawl_delay("0"), Some(Duration::ZERO));
+    assert_eq!(crawl_delay("60"), Some(MAX_CRAWL_DELAY));
+}
+
+#[test]
+fn crawl_delay_ignores_invalid_and_caps_huge_values() {
+    for invalid in ["-1", "NaN", "inf", "-inf", "abc", ""] {
+        assert_eq!(crawl_delay(invalid), None, "{invalid}");
+    }
+    for huge in ["61", "86400", "1e30"] {
+        assert_eq!(crawl_delay(huge), Some(MAX_CRAWL_DELAY), "{huge}");
+    }
+    // 잘못된 crawl-delay가 있어도 나머지 규칙은 그대로
+    let robots = Robots::parse("User-agent: *\nCrawl-delay: NaN\nDisallow: /admin\n", ROBOTS_AGENT);
+    assert!(!robots.allows("/admin/x"));
+    assert!(robots.allows("/bbs/list"));
+}
+
+/// robots.txt 요청에는 정한 상태 코드/본문으로, 다른 주소에는 200으로 답하는 서버
+struct RobotsServer {
+    status: StatusCode,
+    body: &'static str,
+    robots_requests: AtomicU32,
+}
+
+impl RobotsServer {
+    fn new(status: StatusCode, body: &'static str) -> Arc<Self> {
+        Arc::new(RobotsServer {
+            status,
+            body,
+            robots_requests: AtomicU32::new(0),
+        })
+    }
+}
+
+#[async_trait]
+impl Transport for RobotsServer {
+    async fn get(&self, req: TransportRequest<'_>) -> Result<TransportResponse, ScrapeError> {
+        let (status, body) = if req.url.ends_with("/robots.txt") {
+            self.robots_requests.fetch_add(1, Ordering::SeqCst);
+            (self.status, self.body)
+        } else {
+            (StatusCode::OK, "<html></html>")
+        };
+        Ok(TransportResponse {
+            status,
+            headers: HeaderMap::new(),
+            body: body.to_string(),
+        })
+    }
+}
+
+fn client(server: &Arc<RobotsServer>) -> HttpClient {
+    let config = HttpConfig {
+        retry_base: Duration::from_millis(1),
+        host_rps: 0.0,
+        ..HttpConfig::default()
+    };
+    HttpClient::with_transport(&config, server.clone())
+}
+
+const PAGE: &str = "https://www.example.ac.kr/bbs/list?page=1";
+
+#[tokio::test]
+async fn missing_robots_allows_everything() {
+    let server = RobotsServer::new(StatusCode::NOT_FOUND, "");
+    assert!(client(&server).get_html("test", PAGE, PAGE).await.is_ok());
+    assert_eq!(server.robots_requests.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn disallow_rule_is_robots_disallowed() {
+    let server = RobotsServer::new(StatusCode::OK, "User-agent: *\nDisallow: /bbs/\n");
+    let err = client(&server).get_html("test", PAGE, PAGE).await.unwrap_err();
+    assert!(matches!(err, ScrapeError::RobotsDisallowed { .. }), "{err}");
+    assert!(!err.is_transient());
+}
+
+#[tokio::test]
+async fn server_error_on_robots_is_transient_and_retried() {
+    let server = RobotsServer::new(StatusCode::SERVICE_UNAVAILABLE, "");
+    let http = client(&server);
+    let err = http.get_html("test", PAGE, PAGE).await.unwrap_err();
+    assert!(matches!(err, ScrapeError::RobotsUnreachable { .. }), "{err}");
+    assert!(err.is_transient());
+    // 페이지와 같은 재시도 경로: 첫 요청 + max_retries(2)
+    assert_eq!(server.robots_requests.load(Ordering::SeqCst), 3);
+    assert_eq!(http.breakers().snapshot()[0].consecutive_failures, 1);
+
+    // 못 받은 robots.txt는 캐시하지 않으므로 다음 요청이 다시 받아 봄
+    let _ = http.get_html("test", PAGE, PAGE).await;
+    assert_eq!(server.robots_requests.load(Ordering::SeqCst), 6);
+}
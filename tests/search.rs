@@ -0,0 +1,83 @@
+// tests/search.rs
+// 저장된 공지 검색: 띄어쓰기/조사와 상관없는 한글 검색, 첨부 이름, 학교/기간 조건, HTML 결과 페이지
+
+use chrono::{DateTime, FixedOffset, TimeZone};
+use univ_crawler::model::{self, kst, Attachment, Notice};
+use univ_crawler::search::{self, SearchQuery, SearchResult};
+use univ_crawler::store::Store;
+
+fn at(month: u32, day: u32) -> DateTime<FixedOffset> {
+    kst().with_ymd_and_hms(2025, month, day, 9, 0, 0).unwrap()
+}
+
+fn notice(school: &str, id: &str, title: &str, date: &str) -> Notice {
+    let mut n = Notice::new(school, title.to_string(), date.to_string(), format!("https://example.ac.kr/view?id={id}"));
+    n.id = Some(id.to_string());
+    n.published = model::parse_date(date);
+    n
+}
+
+fn guids(store: &Store, query: &SearchQuery) -> Vec<String> {
+    store.search(query).unwrap().iter().map(|s| s.notice.guid()).collect()
+}
+
+#[test]
+fn spacing_and_particles_do_not_break_matches() {
+    assert_eq!(search::tokenize("수강 신청"), search::tokenize("수강신청"));
+    assert_eq!(search::tokenize("휴·복학"), search::tokenize("휴복학"));
+    assert_eq!(search::strip_particle("장학금은"), "장학금");
+    assert_eq!(search::strip_particle("학과"), "학과");
+
+    let store = Store::open_in_memory().unwrap();
+    let mut scholarship = notice("dongduk", "1", "교외 장학생 선발", "2025.02.03");
+    scholarship.body_html = Some("<p>재단 장학금을 신청하세요.</p>".into());
+    let mut application = notice("dongduk", "2", "1학기 수강 신청 안내", "2025.02.04");
+    application.attachments = vec![Attachment::new("수강편람.pdf".into(), "https://example.ac.kr/f/1".into(), None)];
+    store.upsert("dongduk", &[scholarship, application], at(2, 5)).unwrap();
+
+    assert_eq!(guids(&store, &SearchQuery::new("수강신청")), ["dongduk:2"]);
+    assert_eq!(guids(&store, &SearchQuery::new("장학금은")), ["dongduk:1"]);
+    assert_eq!(guids(&store, &SearchQuery::new("수강편람")), ["dongduk:2"]);
+    assert!(guids(&store, &SearchQuery::new("등록금")).is_empty());
+}
+
+#[test]
+fn school_and_date_filters() {
+    let store = Store::open_in_memory().unwrap();
+    store
+        .upsert("dongduk", &[notice("dongduk", "3", "국가장학금 2차 신청", "2025.01.10")], at(1, 10))
+        .unwrap();
+    store
+        .upsert("dongduk", &[notice("dongduk", "4", "교내 장학금 안내", "2025.02.10")], at(2, 10))
+        .unwrap();
+    store
+        .upsert("seoul", &[notice("seoul", "5", "장학금 지급 일정", "2025.02.11")], at(2, 11))
+        .unwrap();
+
+    let mut all = guids(&store, &SearchQuery::new("장학금"));
+    all.sort();
+    assert_eq!(all, ["dongduk:3", "dongduk:4", "seoul:5"]);
+
+    let dongduk = SearchQuery::new("장학금").school(Some("동덕")).unwrap();
+    assert_eq!(dongduk.school, Some("dongduk"));
+    let february = dongduk.dates(Some("2025-02-01"), Some("2025-02-10")).unwrap();
+    assert_eq!(guids(&store, &february), ["dongduk:4"]);
+
+    let args: Vec<String> = ["장학금", "--school", "seoul", "--to", "2025-01-31"].map(String::from).to_vec();
+    assert!(guids(&store, &SearchQuery::from_args(&args).unwrap()).is_empty());
+    assert!(SearchQuery::new("장학금").dates(Some("2025/02/01"), None).is_err());
+}
+
+#[test]
+fn html_results_escape_titles_and_query() {
+    let store = Store::open_in_memory().unwrap();
+    let n = notice("seoul", "6", "<b>장학금</b> & 등록금 안내", "2025.02.12");
+    store.upsert("seoul", &[n], at(2, 12)).unwrap();
+    let results: Vec<SearchResult> = store.search(&SearchQuery::new("장학금")).unwrap().iter().map(SearchResult::from).collect();
+
+    let html = search::results_html("장학금\"><script>", &results);
+    assert!(html.contains(r#"<a href="https://example.ac.kr/view?id=6">&lt;b&gt;장학금&lt;/b&gt; &amp; 등록금 안내</a>"#), "{html}");
+    assert!(html.contains(r#"value="장학금&quot;&gt;&lt;script&gt;""#), "{html}");
+    assert!(html.contains("seoul · 2025-02-12"), "{html}");
+    assert!(!html.contains("<script>"));
+}
@@ -0,0 +1,42 @@
+// tests/singleflight.rs
+// 학교별 단일 수집: 동시 요청은 한 번만 수집하고 나머지는 합류, 리더가 끊기면 다른 요청이 이어받음
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+use univ_crawler::singleflight::SingleFlight;
+
+#[tokio::test]
+async fn concurrent_requests_share_one_crawl() {
+    let flights = SingleFlight::<usize>::new();
+    let crawls = AtomicUsize::new(0);
+    let crawl = || async {
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        crawls.fetch_add(1, Ordering::SeqCst) + 1
+    };
+
+    let results = futures::future::join_all((0..20).map(|_| flights.run("seoul", crawl))).await;
+    assert_eq!(results, vec![1; 20]);
+    assert_eq!(crawls.load(Ordering::SeqCst), 1);
+    assert_eq!(flights.coalesced().get("seoul"), Some(&19));
+
+    // 끝난 뒤에 온 요청은 새로 수집, 다른 학교는 따로
+    assert_eq!(flights.run("seoul", crawl).await, 2);
+    assert_eq!(flights.run("dongduk", crawl).await, 3);
+    assert_eq!(flights.coalesced().get("dongduk"), None);
+}
+
+#[tokio::test]
+async fn follower_takes_over_when_leader_is_cancelled() {
+    let flights = SingleFlight::<&str>::new();
+    let leader = flights.run("seoul", || async {
+        tokio::time::sleep(Duration::from_secs(60)).await;
+        "leader"
+    });
+    let follower = flights.run("seoul", || async { "follower" });
+
+    // 리더는 10ms 뒤에 버려짐 (클라이언트 연결 종료). 합류했던 요청이 직접 수집
+    let cancelled = tokio::time::timeout(Duration::from_millis(10), leader);
+    let (cancelled, result) = tokio::join!(cancelled, follower);
+    assert!(cancelled.is_err());
+    assert_eq!(result, "follower");
+}
@@ -0,0 +1,271 @@
+// tests/store.rs
+// 공지 저장소: 처음 본 시각/내용 변경 시각 기록, 목록만 읽은 실행의 병합, 날짜 없는 글의 게시일,
+// 수집 결과 분류(CrawlDiff), 수정 이력, 이전 피드 가져오기와 보존 범위, 피드 파일 일괄 교체, 시각 오프셋 통일
+
+use chrono::{DateTime, Duration, FixedOffset, TimeZone};
+use univ_crawler::model::{kst, Attachment, Notice, PinnedMode};
+use univ_crawler::diff::{self, Change, CrawlDiff};
+use univ_crawler::schools;
+use univ_crawler::storage;
+use univ_crawler::store::Store;
+
+fn at(day: u32) -> DateTime<FixedOffset> {
+    kst().with_ymd_and_hms(2025, 2, day, 9, 0, 0).unwrap()
+}
+
+fn notice(id: &str, title: &str, date: &str) -> Notice {
+    let mut n = Notice::new(
+        "seoul",
+        title.to_string(),
+        date.to_string(),
+        format!("https://www.swu.ac.kr/front/boardview.do?pkid={id}"),
+    );
+    n.id = Some(id.to_string());
+    n
+}
+
+/// (새 글, 수정, 그대로, 삭제) 건수
+fn counts(d: &CrawlDiff) -> (usize, usize, usize, usize) {
+    (d.new.len(), d.edited.len(), d.unchanged.len(), d.removed.len())
+}
+
+#[test]
+fn tracks_first_seen_and_last_changed() {
+    let store = Store::open_in_memory().unwrap();
+    assert_eq!(store.schema_version().unwrap(), 5);
+
+    let diff = store.upsert("seoul", &[notice("1", "학위수여식 안내", "2025.02.04")], at(4)).unwrap();
+    assert_eq!(counts(&diff), (1, 0, 0, 0));
+
+    let edited = notice("1", "학위수여식 안내 (수정)", "2025.02.04");
+    let diff = store.upsert("seoul", std::slice::from_ref(&edited), at(5)).unwrap();
+    assert_eq!(counts(&diff), (0, 1, 0, 0));
+    assert_eq!(
+        diff.edited[0].changes,
+        vec![Change::Title {
+            before: "학위수여식 안내".into(),
+            after: "학위수여식 안내 (수정)".into()
+        }]
+    );
+
+    // 조회수만 바뀐 건 내용 변경이 아님
+    let mut viewed = edited;
+    viewed.views = Some(500);
+    let diff = store.upsert("seoul", &[viewed], at(6)).unwrap();
+    assert_eq!(counts(&diff), (0, 0, 1, 0));
+
+    let stored = store.recent("seoul", PinnedMode::Include, 10).unwrap();
+    assert_eq!(stored.len(), 1);
+    assert_eq!(stored[0].notice.title, "학위수여식 안내 (수정)");
+    assert_eq!(stored[0].notice.views, Some(500));
+    assert_eq!(stored[0].notice.first_seen, Some(at(4)));
+    assert_eq!(stored[0].last_changed, at(5));
+    assert_eq!(stored[0].last_seen, at(6));
+}
+
+#[test]
+fn list_only_crawl_keeps_details_and_undated_notice_uses_first_seen() {
+    let store = Store::open_in_memory().unwrap();
+    let mut detailed = notice("2", "근로장학생 모집", "2025.02.05");
+    detailed.body_html = Some("<p>본문</p>".to_string());
+    detailed.published = Some(at(5) + Duration::hours(5));
+    detailed.attachments = vec![Attachment::new("신청서.hwp".into(), "https://www.swu.ac.kr/file/1".into(), None)];
+    let undated = notice("3", "날짜 없는 글", "-");
+    store.upsert("seoul", &[detailed, undated], at(7)).unwrap();
+
+    // 상세 없이 목록만 다시 읽어도 본문/첨부/게시 시각은 유지, 변경으로 치지 않음
+    let diff = store
+        .upsert("seoul", &[notice("2", "근로장학생 모집", "2025.02.05"), notice("3", "날짜 없는 글", "-")], at(8))
+        .unwrap();
+    assert_eq!(counts(&diff), (0, 0, 2, 0));
+
+    let stored: Vec<Notice> = store
+        .recent("seoul", PinnedMode::Include, 10)
+        .unwrap()
+        .into_iter()
+        .map(|s| s.notice)
+        .collect();
+    // 날짜 없는 글은 처음 본 시각(2/7)이 게시일이라 2/5 글보다 앞
+    assert_eq!(stored[0].title, "날짜 없는 글");
+    assert_eq!(stored[0].pub_date(), at(7));
+    assert_eq!(stored[1].body_html.as_deref(), Some("<p>본문</p>"));
+    assert_eq!(stored[1].attachments.len(), 1);
+    assert_eq!(stored[1].pub_date_rfc2822(), "Wed, 5 Feb 2025 14:00:00 +0900");
+}
+
+#[test]
+fn pinned_flag_is_cleared_when_notice_leaves_the_top() {
+    let store = Store::open_in_memory().unwrap();
+    let mut pinned = notice("4", "등록금 납부 안내", "2025.02.01");
+    pinned.pinned = true;
+    store.upsert("seoul", &[pinned, notice("5", "새 글", "2025.02.09")], at(9)).unwrap();
+    assert_eq!(store.recent("seoul", PinnedMode::Only, 10).unwrap().len(), 1);
+
+    store.upsert("seoul", &[notice("5", "새 글", "2025.02.09")], at(10)).unwrap();
+    assert!(store.recent("seoul", PinnedMode::Only, 10).unwrap().is_empty());
+    assert_eq!(store.recent("seoul", PinnedMode::Exclude, 10).unwrap().len(), 2);
+}
+
+#[test]
+fn missing_notice_inside_crawled_range_becomes_tombstone() {
+    let store = Store::open_in_memory().unwrap();
+    let board = [
+        notice("13", "넷째 글", "2025.02.13"),
+        notice("12", "셋째 글", "2025.02.12"),
+        notice("11", "둘째 글", "2025.02.11"),
+        notice("10", "첫 글", "2025.02.10"),
+    ];
+    store.upsert("seoul", &board, at(13)).unwrap();
+
+    // 12일 글이 지워지고 14일 글이 올라옴. 10일 글은 2페이지로 밀려 이번 범위 밖 → 삭제가 아님
+    // (가장 오래된 11일은 페이지 경계일 수 있어 12일부터 판단)
+    let crawl = [notice("14", "다섯째 글", "2025.02.14"), board[0].clone(), board[2].clone()];
+    let diff = store.upsert("seoul", &crawl, at(14)).unwrap();
+    assert_eq!(counts(&diff), (1, 0, 2, 1));
+    assert_eq!(diff.removed[0].guid, "seoul:12");
+    assert_eq!(diff.removed[0].removed_at, at(14));
+
+    let titles: Vec<String> = store
+        .recent("seoul", PinnedMode::Include, 10)
+        .unwrap()
+        .into_iter()
+        .map(|s| s.notice.title)
+        .collect();
+    assert_eq!(titles, ["다섯째 글", "넷째 글", "둘째 글", "첫 글"]);
+
+    // 다시 나타나면 새 글로 보고 피드에도 돌아옴
+    let diff = store.upsert("seoul", &[crawl[0].clone(), board[0].clone(), board[1].clone()], at(15)).unwrap();
+    assert_eq!(counts(&diff), (1, 0, 2, 0));
+    assert_eq!(store.recent("seoul", PinnedMode::Include, 10).unwrap().len(), 5);
+}
+
+#[test]
+fn edits_are_kept_as_revisions_with_readable_diff() {
+    let store = Store::open_in_memory().unwrap();
+    let mut v1 = notice("6", "전과 신청 안내", "2025.02.03");
+    v1.body_html = Some("<p>전과 신청을 받습니다.</p><p>신청 기한: 2월 10일</p><p>문의: 학사팀</p>".into());
+    store.upsert("seoul", std::slice::from_ref(&v1), at(3)).unwrap();
+    assert!(store.last_changes("seoul:6").unwrap().is_none());
+
+    let mut v2 = v1.clone();
+    v2.title = "전과 신청 안내 (기한 연장)".into();
+    v2.body_html = Some("<p>전과 신청을 받습니다.</p><p>신청 기한: 2월 14일</p><p>문의: 학사팀</p>".into());
+    store.upsert("seoul", &[v2], at(5)).unwrap();
+
+    let (stored, revisions) = store.history("seoul:6").unwrap().unwrap();
+    assert_eq!(stored.notice.title, "전과 신청 안내 (기한 연장)");
+    // Atom updated = 마지막 수정 시각
+    assert_eq!(stored.notice.updated(), at(5));
+    assert_eq!(revisions.iter().map(|r| r.seq).collect::<Vec<_>>(), [1, 2]);
+    assert_eq!(revisions[0].title, "전과 신청 안내");
+    assert_ne!(revisions[0].body_hash, revisions[1].body_hash);
+    assert_eq!(
+        revisions[0].render_diff(&revisions[1]),
+        "제목\n  - 전과 신청 안내\n  + 전과 신청 안내 (기한 연장)\n\
+         본문\n    전과 신청을 받습니다.\n  - 신청 기한: 2월 10일\n  + 신청 기한: 2월 14일\n    문의: 학사팀\n"
+    );
+
+    let (changed_at, changes) = store.last_changes("seoul:6").unwrap().unwrap();
+    assert_eq!(changed_at, at(5));
+    assert_eq!(
+        diff::change_note(changed_at, &changes),
+        "수정됨 (2025-02-05 09:00): 제목: 전과 신청 안내 → 전과 신청 안내 (기한 연장), 본문"
+    );
+}
+
+#[test]
+fn previous_feed_is_imported_and_archive_is_trimmed_by_age() {
+    let mut old = notice("7", "[장학] 교외 장학금 안내", "2025.01.02");
+    old.category = Some("장학".into());
+    old.author = Some("학생처".into());
+    old.summary = Some("교외 장학금 신청을 받습니다.".into());
+    let recent = notice("8", "도서관 휴관 안내", "2025.02.08");
+
+    // 지난 배포의 rss.xml → 공지로 되살림
+    let channel = schools::find("seoul").unwrap().create_rss(&[recent.clone(), old]);
+    let path = std::env::temp_dir().join(format!("univ-crawler-prev-{}.xml", std::process::id()));
+    let path = path.to_str().unwrap();
+    storage::save_rss_xml(&channel, path).unwrap();
+    let restored = storage::load_feed_notices(path, "seoul");
+    std::fs::remove_file(path).ok();
+    assert_eq!(restored.iter().map(Notice::guid).collect::<Vec<_>>(), ["seoul:8", "seoul:7"]);
+    assert_eq!(restored[1].author.as_deref(), Some("학생처"));
+    assert_eq!(restored[1].category.as_deref(), Some("장학"));
+    assert_eq!(restored[1].summary.as_deref(), Some("교외 장학금 신청을 받습니다."));
+    assert_eq!(restored[1].pub_date_rfc2822(), "Thu, 2 Jan 2025 00:00:00 +0900");
+
+    // 빈 저장소로 가져오기. 이미 있는 글은 다시 가져오지 않음
+    let store = Store::open_in_memory().unwrap();
+    assert_eq!(store.import(&restored, at(9)).unwrap(), 2);
+    assert_eq!(store.import(&restored, at(9)).unwrap(), 0);
+
+    // 이번 수집에는 8번만 보임 (7번은 다음 페이지) → 저장소에는 둘 다 남음
+    let diff = store.upsert("seoul", &[recent], at(10)).unwrap();
+    assert_eq!(counts(&diff), (0, 0, 1, 0));
+    assert_eq!(store.recent("seoul", PinnedMode::Include, 10).unwrap().len(), 2);
+
+    // 30일 보존: 게시일도 마지막으로 본 날도 오래된 7번은 피드에서 빠짐
+    let since = Some(at(10) - Duration::days(30));
+    let kept = store.recent_since("seoul", PinnedMode::Include, 10, since).unwrap();
+    assert_eq!(kept.iter().map(|s| s.notice.guid()).collect::<Vec<_>>(), ["seoul:8"]);
+}
+
+#[test]
+fn feed_files_are_replaced_all_or_nothing() {
+    let dir = std::env::temp_dir().join(format!("univ-crawler-files-{}", std::process::id()));
+    let dir = dir.to_str().unwrap();
+    let files = |tag: &str| {
+        ["rss.xml", "atom.xml", "feed.json"].map(|name| (format!("{dir}/{name}"), format!("{tag} {name}")))
+    };
+    storage::write_files_atomically(&files("v1")).unwrap();
+
+    // feed.json 임시 파일 자리를 디렉터리로 막아 쓰기 실패 → 어느 파일도 바뀌지 않고 임시 파일도 남지 않음
+    std::fs::create_dir_all(format!("{dir}/feed.json.tmp")).unwrap();
+    assert!(storage::write_files_atomically(&files("v2")).is_err());
+    for (path, contents) in files("v1") {
+        assert_eq!(std::fs::read_to_string(path).unwrap(), contents);
+    }
+    assert!(!std::path::Path::new(&format!("{dir}/rss.xml.tmp")).exists());
+
+    std::fs::remove_dir(format!("{dir}/feed.json.tmp")).unwrap();
+    storage::write_files_atomically(&files("v2")).unwrap();
+    assert_eq!(std::fs::read_to_string(format!("{dir}/atom.xml")).unwrap(), "v2 atom.xml");
+    std::fs::remove_dir_all(dir).ok();
+}
+
+#[test]
+fn timestamps_with_other_offsets_are_stored_as_kst() {
+    // 가져온 피드의 게시일이 UTC여도 KST 글과 올바른 순서로 비교됨
+    // (2월 3일 20:00 UTC = 2월 4일 05:00 KST → 2월 4일 KST 0시 글보다 최신)
+    let mut imported = notice("1", "UTC로 적힌 글", "");
+    imported.published = Some(DateTime::parse_from_rfc2822("Mon, 3 Feb 2025 20:00:00 +0000").unwrap());
+    let local = notice("2", "KST 글", "2025.02.04");
+    let store = Store::open_in_memory().unwrap();
+    store.import(&[imported, local], at(5)).unwrap();
+    let recent = store.recent("seoul", PinnedMode::Include, 10).unwrap();
+    let guids: Vec<_> = recent.iter().map(|s| s.notice.guid()).collect();
+    assert_eq!(guids, ["seoul:1", "seoul:2"]);
+    assert_eq!(recent[0].notice.pub_date_rfc2822(), "Tue, 4 Feb 2025 05:00:00 +0900");
+
+    // 예전 저장소에 남은 다른 오프셋의 시각은 스키마를 올릴 때 KST로 다시 씀
+    let path = std::env::temp_dir().join(format!("univ-crawler-offsets-{}.db", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    drop(Store::open(&path).unwrap());
+    {
+        let conn = rusqlite::Connection::open(&path).unwrap();
+        conn.execute_batch(
+            "INSERT INTO notices (guid, school, title, date_raw, published, url, first_seen, last_seen, last_changed)
+             VALUES ('seoul:9', 'seoul', '옛 글', '', '2025-02-03 20:00:00+00:00', 'https://example.ac.kr/9',
+                     '2025-02-03 23:30:00-01:00', '2025-02-05 09:00:00+09:00', '2025-02-05 09:00:00+09:00');
+             PRAGMA user_version = 4;",
+        )
+        .unwrap();
+    }
+    let store = Store::open(&path).unwrap();
+    let (stored, _) = store.history("seoul:9").unwrap().unwrap();
+    assert_eq!(stored.notice.published.unwrap().to_rfc3339(), "2025-02-04T05:00:00+09:00");
+    assert_eq!(stored.notice.first_seen.unwrap().to_rfc3339(), "2025-02-04T09:30:00+09:00");
+    assert_eq!(stored.last_seen.to_rfc3339(), "2025-02-05T09:00:00+09:00");
+    drop(store);
+    std::fs::remove_file(&path).ok();
+}
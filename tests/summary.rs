@@ -0,0 +1,43 @@
+// tests/summary.rs
+// 원샷 실행 종료 코드: 모두 갱신 0, 일부 실패 2, 모두 실패 1
+
+use univ_crawler::health::HealthStatus;
+use univ_crawler::summary::{RunSummary, SchoolOutcome};
+
+fn updated() -> SchoolOutcome {
+    SchoolOutcome::Updated {
+        items: 10,
+        health: HealthStatus::Ok,
+    }
+}
+
+fn failed(transient: bool) -> SchoolOutcome {
+    SchoolOutcome::Failed {
+        reason: "HTTP 503".to_string(),
+        transient,
+    }
+}
+
+fn summary(outcomes: Vec<SchoolOutcome>) -> RunSummary {
+    let mut s = RunSummary::default();
+    for (o, key) in outcomes.into_iter().zip(["sookmyung", "dongduk", "seoul"]) {
+        s.push(key, key, o);
+    }
+    s
+}
+
+#[test]
+fn exit_codes_distinguish_all_ok_partial_and_all_failed() {
+    assert_eq!(summary(vec![updated(), updated(), updated()]).exit_code(), 0);
+    assert_eq!(summary(vec![failed(true), updated(), updated()]).exit_code(), 2);
+    assert_eq!(summary(vec![updated(), failed(false), updated()]).exit_code(), 2);
+    assert_eq!(summary(vec![failed(true), failed(false), failed(true)]).exit_code(), 1);
+}
+
+#[test]
+fn markdown_table_lists_every_school() {
+    let md = summary(vec![updated(), failed(true), failed(false)]).markdown();
+    assert_eq!(md.lines().count(), 2 + 3);
+    assert!(md.contains("| sookmyung | 갱신 | 10 |  |"), "{md}");
+    assert!(md.contains("| dongduk | 일시 오류 — 기존 유지 | - | HTTP 503 |"), "{md}");
+}
@@ -0,0 +1,38 @@
+// tests/transport.rs
+// 실제 네트워크 전송: 본문을 받다 연결이 끊기면 재시도할 수 있는 전송 오류로 분류
+
+use reqwest::header::HeaderMap;
+use reqwest::Client;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use univ_crawler::error::ScrapeError;
+use univ_crawler::transport::{ReqwestTransport, Transport, TransportRequest};
+
+#[tokio::test]
+async fn truncated_body_is_transient_transport_error() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        let (mut socket, _) = listener.accept().await.unwrap();
+        let mut buf = [0u8; 1024];
+        let _ = socket.read(&mut buf).await;
+        // Content-Length보다 짧게 보내고 연결을 끊음
+        let _ = socket
+            .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 100\r\n\r\n<html>")
+            .await;
+    });
+
+    let url = format!("http://{addr}/bbs/list");
+    let transport = ReqwestTransport::new(Client::new());
+    let err = transport
+        .get(TransportRequest {
+            school: "test",
+            url: &url,
+            headers: HeaderMap::new(),
+        })
+        .await
+        .err()
+        .expect("잘린 본문은 오류여야 함");
+    assert!(matches!(err, ScrapeError::Transport { .. }), "{err}");
+    assert!(err.is_transient());
+}